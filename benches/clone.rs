@@ -0,0 +1,23 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use parallel_vec::ParallelVec;
+
+fn bench_clone_2(c: &mut Criterion, size: usize) {
+    let vec: Vec<(u64, u64)> = (0..size as u64).map(|i| (i, i * 2)).collect();
+    c.bench_function(&format!("clone_vec_u64_2x_{}", size), |b| {
+        b.iter(|| black_box(vec.clone()))
+    });
+    let vec = ParallelVec::from(vec);
+    c.bench_function(&format!("clone_parallelvec_u64_2x_{}", size), |b| {
+        b.iter(|| black_box(vec.clone()))
+    });
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    for size in [1000, 100000] {
+        bench_clone_2(c, size);
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,27 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+use parallel_vec::ParallelVec;
+
+fn row(i: usize) -> (String, Vec<u8>, u64) {
+    (i.to_string(), vec![0; i % 16], i as u64)
+}
+
+fn bench_drop_3(c: &mut Criterion, size: usize) {
+    let vec: Vec<_> = (0..size).map(row).collect();
+    c.bench_function(&format!("drop_vec_3x_{}", size), |b| {
+        b.iter_batched(|| vec.clone(), drop, BatchSize::LargeInput)
+    });
+    let vec: ParallelVec<_> = (0..size).map(row).collect();
+    c.bench_function(&format!("drop_parallelvec_3x_{}", size), |b| {
+        b.iter_batched(|| vec.clone(), drop, BatchSize::LargeInput)
+    });
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    for size in [1000, 100000] {
+        bench_drop_3(c, size);
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,36 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+use parallel_vec::ParallelVec;
+
+fn bench_extend_3(c: &mut Criterion, size: usize) {
+    let vec: ParallelVec<_> = (0..size).map(|i| (i as u32, i as u64, i as f32)).collect();
+    c.bench_function(&format!("extend_rows_parallelvec_3x_{}", size), |b| {
+        b.iter_batched(
+            || vec.clone(),
+            |other| {
+                let mut dst = ParallelVec::new();
+                dst.extend(other);
+                dst
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    c.bench_function(&format!("extend_vec_parallelvec_3x_{}", size), |b| {
+        b.iter_batched(
+            || vec.clone(),
+            |other| {
+                let mut dst = ParallelVec::new();
+                dst.extend([other]);
+                dst
+            },
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    bench_extend_3(c, 100000);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,48 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+
+use parallel_vec::ParallelVec;
+
+fn bench_from_iter_2(c: &mut Criterion, size: usize) {
+    c.bench_function(&format!("from_iter_vec_2x_{}", size), |b| {
+        b.iter(|| {
+            (0..black_box(size))
+                .map(|i| (i as u32, i as u64))
+                .collect::<Vec<_>>()
+        })
+    });
+    c.bench_function(&format!("from_iter_parallelvec_2x_{}", size), |b| {
+        b.iter(|| {
+            (0..black_box(size))
+                .map(|i| (i as u32, i as u64))
+                .collect::<ParallelVec<_>>()
+        })
+    });
+}
+
+fn bench_from_aos_2(c: &mut Criterion, size: usize) {
+    let rows: Vec<_> = (0..size).map(|i| (i as u32, i as u64)).collect();
+    c.bench_function(&format!("from_aos_iter_parallelvec_2x_{}", size), |b| {
+        b.iter_batched(
+            || rows.clone(),
+            |rows| rows.into_iter().collect::<ParallelVec<_>>(),
+            BatchSize::LargeInput,
+        )
+    });
+    c.bench_function(&format!("from_aos_parallelvec_2x_{}", size), |b| {
+        b.iter_batched(
+            || rows.clone(),
+            ParallelVec::from_aos,
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    for size in [1000, 100000] {
+        bench_from_iter_2(c, size);
+        bench_from_aos_2(c, size);
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
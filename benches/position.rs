@@ -0,0 +1,39 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use parallel_vec::ParallelVec;
+
+fn bench_position_2(c: &mut Criterion, size: usize) {
+    let vec: ParallelVec<(u32, u64)> = (0..size).map(|i| (i as u32, i as u64)).collect();
+    let needle = (size - 1) as u32;
+    c.bench_function(&format!("position_parallelvec_iter_2x_{}", size), |b| {
+        b.iter(|| vec.iter().position(|(a, _)| *a == black_box(needle)))
+    });
+    c.bench_function(
+        &format!("position_parallelvec_field_position_of_2x_{}", size),
+        |b| b.iter(|| vec.field_position_of::<0>(black_box(needle))),
+    );
+}
+
+fn bench_position_4(c: &mut Criterion, size: usize) {
+    let vec: ParallelVec<(u8, u64, u64, u64)> = (0..size)
+        .map(|i| ((i % 255) as u8, i as u64, i as u64, i as u64))
+        .collect();
+    let needle = 255u8;
+    c.bench_function(&format!("position_parallelvec_iter_4x_{}", size), |b| {
+        b.iter(|| vec.iter().position(|(a, _, _, _)| *a == black_box(needle)))
+    });
+    c.bench_function(
+        &format!("position_parallelvec_field_position_of_4x_{}", size),
+        |b| b.iter(|| vec.field_position_of::<0>(black_box(needle))),
+    );
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    for size in [1000, 100000, 1000000] {
+        bench_position_2(c, size);
+        bench_position_4(c, size);
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
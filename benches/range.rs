@@ -0,0 +1,44 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use parallel_vec::ParallelVec;
+use rand::*;
+
+fn bench_range_2(c: &mut Criterion, size: usize) {
+    let mut rng = rand::thread_rng();
+    let ranges: Vec<(usize, usize)> = (0..1000)
+        .map(|_| {
+            let a = rng.next_u32() as usize % size;
+            let b = rng.next_u32() as usize % size;
+            (a.min(b), a.max(b))
+        })
+        .collect();
+    let vec = ParallelVec::from(vec![(0u32, 1u64)]).repeat(size);
+    c.bench_function(&format!("range_index_parallelvec_2x_{}", size), |b| {
+        b.iter(|| {
+            for &(start, end) in ranges.iter() {
+                black_box(vec.index(black_box(start)..black_box(end)).len());
+            }
+        })
+    });
+    c.bench_function(
+        &format!("range_get_unchecked_parallelvec_2x_{}", size),
+        |b| {
+            b.iter(|| {
+                for &(start, end) in ranges.iter() {
+                    let slice =
+                        unsafe { vec.get_unchecked_range(black_box(start)..black_box(end)) };
+                    black_box(slice.len());
+                }
+            })
+        },
+    );
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    for size in [1000, 100000, 1000000] {
+        bench_range_2(c, size);
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
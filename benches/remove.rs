@@ -0,0 +1,30 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+
+use parallel_vec::ParallelVec;
+
+fn bench_remove_3(c: &mut Criterion, size: usize) {
+    let vec: ParallelVec<_> = (0..size).map(|i| (i as u32, i as u64, [0u8; 16])).collect();
+    c.bench_function(&format!("remove_front_parallelvec_3x_{}", size), |b| {
+        b.iter_batched(
+            || vec.clone(),
+            |mut vec| black_box(vec.remove(0)),
+            BatchSize::LargeInput,
+        )
+    });
+    c.bench_function(&format!("remove_back_parallelvec_3x_{}", size), |b| {
+        b.iter_batched(
+            || vec.clone(),
+            |mut vec| black_box(vec.remove(size - 1)),
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    for size in [1000, 100000, 1000000] {
+        bench_remove_3(c, size);
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,32 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+
+use parallel_vec::ParallelVec;
+use rand::*;
+
+fn bench_sort_2(c: &mut Criterion, size: usize) {
+    let mut rng = rand::thread_rng();
+    let vec: ParallelVec<(u32, u64)> = (0..size).map(|i| (rng.next_u32(), i as u64)).collect();
+    c.bench_function(&format!("sort_by_parallelvec_2x_{}", size), |b| {
+        b.iter_batched(
+            || vec.clone(),
+            |mut vec| vec.sort_by(|(a, _), (b, _)| black_box(a).cmp(b)),
+            BatchSize::LargeInput,
+        )
+    });
+    c.bench_function(&format!("par_sort_by_parallelvec_2x_{}", size), |b| {
+        b.iter_batched(
+            || vec.clone(),
+            |mut vec| vec.par_sort_by(|(a, _), (b, _)| black_box(a).cmp(b)),
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    for size in [1000, 100000, 1000000] {
+        bench_sort_2(c, size);
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
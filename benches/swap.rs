@@ -0,0 +1,80 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use parallel_vec::ParallelVec;
+use rand::*;
+
+fn bench_swap_2(c: &mut Criterion, size: usize) {
+    let small = (0u32, 1u32);
+    let mut rng = rand::thread_rng();
+    let pairs: Vec<(usize, usize)> = (0..1000)
+        .map(|_| {
+            (
+                rng.next_u32() as usize % size,
+                rng.next_u32() as usize % size,
+            )
+        })
+        .collect();
+    let mut vec = ParallelVec::from(vec![small]).repeat(size);
+    c.bench_function(&format!("swap_parallelvec_small_2x_{}", size), |b| {
+        b.iter(|| {
+            for &(x, y) in pairs.iter() {
+                vec.swap(black_box(x), black_box(y));
+            }
+        })
+    });
+    c.bench_function(
+        &format!("swap_unchecked_parallelvec_small_2x_{}", size),
+        |b| {
+            b.iter(|| {
+                for &(x, y) in pairs.iter() {
+                    unsafe { vec.swap_unchecked(black_box(x), black_box(y)) };
+                }
+            })
+        },
+    );
+    c.bench_function(
+        &format!("swap_many_unchecked_parallelvec_small_2x_{}", size),
+        |b| b.iter(|| unsafe { vec.swap_many_unchecked(black_box(&pairs)) }),
+    );
+}
+
+fn bench_swap_big_3(c: &mut Criterion, size: usize) {
+    let big = ([0u64; 32], [0u64; 32], 0u32);
+    let mut rng = rand::thread_rng();
+    let pairs: Vec<(usize, usize)> = (0..1000)
+        .map(|_| {
+            (
+                rng.next_u32() as usize % size,
+                rng.next_u32() as usize % size,
+            )
+        })
+        .collect();
+    let mut vec = [big].repeat(size);
+    c.bench_function(&format!("swap_vec_big_3x_{}", size), |b| {
+        b.iter(|| {
+            for &(x, y) in pairs.iter() {
+                vec.swap(black_box(x), black_box(y));
+            }
+        })
+    });
+    let mut vec = ParallelVec::from(vec![big]).repeat(size);
+    c.bench_function(&format!("swap_rows_parallelvec_big_3x_{}", size), |b| {
+        b.iter(|| {
+            for &(x, y) in pairs.iter() {
+                vec.swap_rows(black_box(x), black_box(y));
+            }
+        })
+    });
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    for size in [1000, 100000, 1000000] {
+        bench_swap_2(c, size);
+    }
+    for size in [1000, 100000] {
+        bench_swap_big_3(c, size);
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,89 @@
+use alloc::alloc::{alloc, dealloc, realloc, Layout};
+use core::ptr::NonNull;
+
+/// An allocator that [`ParallelVec`] can store its columns in.
+///
+/// This mirrors the shape of the unstable `core::alloc::Allocator` API, so that a
+/// [`ParallelVec`] can be parameterized over where its columns are allocated.
+/// Every column of a vector is allocated, grown, and freed through the same
+/// allocator instance. The default allocator is [`Global`].
+///
+/// # Safety
+/// Memory returned from [`allocate`] or [`reallocate`] must be valid for reads
+/// and writes for the requested layout until it is passed to [`deallocate`] or
+/// [`reallocate`] on the same allocator. Moving the allocator must not invalidate
+/// the memory it has returned.
+///
+/// [`ParallelVec`]: crate::ParallelVec
+/// [`allocate`]: Self::allocate
+/// [`reallocate`]: Self::reallocate
+/// [`deallocate`]: Self::deallocate
+pub unsafe trait Allocator {
+    /// Allocates a block of memory for `layout`, or returns `None` if the
+    /// allocation fails.
+    ///
+    /// `layout` never has a size of zero.
+    fn allocate(&self, layout: Layout) -> Option<NonNull<u8>>;
+
+    /// Deallocates the block of memory at `ptr`.
+    ///
+    /// # Safety
+    /// `ptr` must have been allocated by this allocator with the given `layout`.
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout);
+
+    /// Grows or shrinks the block of memory at `ptr` to `new_layout`, copying
+    /// over as much of its contents as fits. Returns `None` if the allocation
+    /// fails, in which case the original block is left untouched.
+    ///
+    /// The default implementation allocates a new block, copies the contents,
+    /// then deallocates the old block.
+    ///
+    /// # Safety
+    /// `ptr` must have been allocated by this allocator with `old_layout`, and
+    /// `new_layout` must have the same alignment as `old_layout` and a non-zero size.
+    unsafe fn reallocate(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Option<NonNull<u8>> {
+        let new_ptr = self.allocate(new_layout)?;
+        let size = old_layout.size().min(new_layout.size());
+        ptr.as_ptr().copy_to_nonoverlapping(new_ptr.as_ptr(), size);
+        self.deallocate(ptr, old_layout);
+        Some(new_ptr)
+    }
+}
+
+/// The global memory allocator.
+///
+/// This is the default [`Allocator`] for [`ParallelVec`], and forwards every
+/// request to the allocator registered with `#[global_allocator]`.
+///
+/// [`ParallelVec`]: crate::ParallelVec
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub struct Global;
+
+unsafe impl Allocator for Global {
+    #[inline]
+    fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+        // SAFE: Callers never request zero-sized layouts.
+        NonNull::new(unsafe { alloc(layout) })
+    }
+
+    #[inline]
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        dealloc(ptr.as_ptr(), layout);
+    }
+
+    #[inline]
+    unsafe fn reallocate(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Option<NonNull<u8>> {
+        debug_assert_eq!(old_layout.align(), new_layout.align());
+        NonNull::new(realloc(ptr.as_ptr(), old_layout, new_layout.size()))
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{ParallelParam, ParallelVec};
+use arbitrary::{size_hint, Arbitrary, Result, Unstructured};
+
+impl<'a, Param> Arbitrary<'a> for ParallelVec<Param>
+where
+    Param: ParallelParam + Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_iter()?.collect()
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_take_rest_iter()?.collect()
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(<usize as Arbitrary>::size_hint(depth), (0, None))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ParallelVec;
+    use arbitrary::{Arbitrary, Unstructured};
+    use std::vec::Vec;
+
+    const BYTES: &[u8] = &[
+        1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+        26, 27, 28, 29, 30, 31, 32,
+    ];
+
+    #[test]
+    fn test_arbitrary_matches_vec() {
+        let mut u = Unstructured::new(BYTES);
+        let vec = ParallelVec::<(u8, u16)>::arbitrary(&mut u).unwrap();
+        let mut u = Unstructured::new(BYTES);
+        let expected = Vec::<(u8, u16)>::arbitrary(&mut u).unwrap();
+        assert!(!expected.is_empty());
+        assert_eq!(vec.len(), expected.len());
+        assert_eq!(vec.into_iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_arbitrary_take_rest_matches_vec() {
+        let vec =
+            ParallelVec::<(u8, u16, i32)>::arbitrary_take_rest(Unstructured::new(BYTES)).unwrap();
+        let expected =
+            Vec::<(u8, u16, i32)>::arbitrary_take_rest(Unstructured::new(BYTES)).unwrap();
+        assert!(!expected.is_empty());
+        assert_eq!(vec.len(), expected.len());
+        assert_eq!(vec.into_iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_arbitrary_empty() {
+        let mut u = Unstructured::new(&[]);
+        let vec = ParallelVec::<(u8, u16)>::arbitrary(&mut u).unwrap();
+        assert!(vec.is_empty());
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{Allocator, Global, ParallelParam, ParallelVec};
+
+/// A view into a single position in a sorted [`ParallelVec`], which may either
+/// be occupied or vacant.
+///
+/// See [`ParallelVec::entry_by_key`].
+///
+/// [`ParallelVec`]: crate::ParallelVec
+/// [`ParallelVec::entry_by_key`]: crate::ParallelVec::entry_by_key
+pub enum Entry<'a, Param: ParallelParam, A: Allocator = Global> {
+    /// An element with a matching key exists.
+    Occupied(OccupiedEntry<'a, Param, A>),
+    /// No element with a matching key exists.
+    Vacant(VacantEntry<'a, Param, A>),
+}
+
+impl<'a, Param: ParallelParam, A: Allocator> Entry<'a, Param, A> {
+    /// Gets the index of the entry in the vector.
+    pub fn index(&self) -> usize {
+        match self {
+            Self::Occupied(entry) => entry.index(),
+            Self::Vacant(entry) => entry.index(),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting `default` if empty, and returns
+    /// mutable references to the fields of the value in the entry.
+    pub fn or_insert(self, default: Param) -> Param::RefMut<'a> {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the result of `default` if empty,
+    /// and returns mutable references to the fields of the value in the entry.
+    pub fn or_insert_with<F: FnOnce() -> Param>(self, default: F) -> Param::RefMut<'a> {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(default()),
+        }
+    }
+}
+
+/// A view into an occupied entry in a sorted [`ParallelVec`]. It is part of the
+/// [`Entry`] enum.
+///
+/// [`ParallelVec`]: crate::ParallelVec
+pub struct OccupiedEntry<'a, Param: ParallelParam, A: Allocator = Global> {
+    pub(crate) vec: &'a mut ParallelVec<Param, A>,
+    pub(crate) index: usize,
+}
+
+impl<'a, Param: ParallelParam, A: Allocator> OccupiedEntry<'a, Param, A> {
+    /// Gets the index of the entry in the vector.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Gets immutable references to the fields of the value in the entry.
+    pub fn get(&self) -> Param::Ref<'_> {
+        // SAFE: The index was in bounds when the entry was created, and the
+        // vector cannot be modified while the entry borrows it.
+        unsafe { Param::as_ref(Param::ptr_at(self.vec.storage, self.index)) }
+    }
+
+    /// Gets mutable references to the fields of the value in the entry.
+    pub fn get_mut(&mut self) -> Param::RefMut<'_> {
+        // SAFE: The index was in bounds when the entry was created, and the entry
+        // holds the only mutable borrow of the vector, which is borrowed from `self`.
+        unsafe { Param::as_mut(Param::ptr_at(self.vec.storage, self.index)) }
+    }
+
+    /// Converts the entry into mutable references to the fields of the value in the
+    /// entry, with a lifetime bound to the vector itself.
+    pub fn into_mut(self) -> Param::RefMut<'a> {
+        // SAFE: The index was in bounds when the entry was created, and the entry's
+        // mutable borrow of the vector for `'a` is consumed here.
+        unsafe { Param::as_mut(Param::ptr_at(self.vec.storage, self.index)) }
+    }
+
+    /// Sets the value of the entry, and returns the entry's old value.
+    pub fn insert(&mut self, value: Param) -> Param {
+        // SAFE: The index was in bounds when the entry was created, and the vector
+        // is mutably borrowed by the entry. The old value is moved out before it
+        // is overwritten, so it is neither dropped twice nor leaked.
+        unsafe {
+            let ptr = Param::ptr_at(self.vec.storage, self.index);
+            let old = Param::read(ptr);
+            Param::write(ptr, value);
+            old
+        }
+    }
+
+    /// Removes the value of the entry from the vector, shifting all elements after
+    /// it down one index, and returns it.
+    pub fn remove(self) -> Param {
+        // The index is always in bounds, so this never returns `None`.
+        self.vec.remove(self.index).unwrap()
+    }
+}
+
+/// A view into a vacant entry in a sorted [`ParallelVec`]. It is part of the
+/// [`Entry`] enum.
+///
+/// [`ParallelVec`]: crate::ParallelVec
+pub struct VacantEntry<'a, Param: ParallelParam, A: Allocator = Global> {
+    pub(crate) vec: &'a mut ParallelVec<Param, A>,
+    pub(crate) index: usize,
+}
+
+impl<'a, Param: ParallelParam, A: Allocator> VacantEntry<'a, Param, A> {
+    /// Gets the index where a value will be inserted to keep the vector sorted.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Inserts `value` at the entry's index, shifting all elements after it up one
+    /// index, and returns mutable references to the fields of the inserted value.
+    pub fn insert(self, value: Param) -> Param::RefMut<'a> {
+        self.vec.insert(self.index, value);
+        // SAFE: The value was just inserted at the index, and the entry's mutable
+        // borrow of the vector for `'a` is consumed here.
+        unsafe { Param::as_mut(Param::ptr_at(self.vec.storage, self.index)) }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::ParallelParam;
+use crate::{Allocator, Global, ParallelParam, ParallelSlice, ParallelVec};
 use core::{
     iter::{DoubleEndedIterator, ExactSizeIterator},
     marker::PhantomData,
@@ -16,6 +16,19 @@ pub struct Iter<'a, Param: ParallelParam> {
     pub(crate) _marker: PhantomData<&'a Param>,
 }
 
+impl<'a, Param: ParallelParam> Iter<'a, Param> {
+    /// Returns the next element without advancing the iterator, or [`None`]
+    /// if the iterator is exhausted.
+    ///
+    /// [`None`]: Option::None
+    pub fn peek(&self) -> Option<Param::Ref<'a>> {
+        if self.remaining == 0 {
+            return None;
+        }
+        unsafe { Some(Param::as_ref(self.ptr)) }
+    }
+}
+
 impl<'a, Param: ParallelParam> Iterator for Iter<'a, Param> {
     type Item = Param::Ref<'a>;
     fn next(&mut self) -> Option<Param::Ref<'a>> {
@@ -33,10 +46,27 @@ impl<'a, Param: ParallelParam> Iterator for Iter<'a, Param> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.remaining, Some(self.remaining))
     }
+
+    fn last(self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        unsafe { Some(Param::as_ref(Param::add(self.ptr, self.remaining - 1))) }
+    }
 }
 
 impl<'a, Param: ParallelParam> ExactSizeIterator for Iter<'a, Param> {}
 
+impl<'a, Param: ParallelParam> Clone for Iter<'a, Param> {
+    fn clone(&self) -> Self {
+        Self {
+            ptr: self.ptr,
+            remaining: self.remaining,
+            _marker: PhantomData,
+        }
+    }
+}
+
 impl<'a, Param: ParallelParam> DoubleEndedIterator for Iter<'a, Param> {
     fn next_back(&mut self) -> Option<Param::Ref<'a>> {
         unsafe {
@@ -48,8 +78,21 @@ impl<'a, Param: ParallelParam> DoubleEndedIterator for Iter<'a, Param> {
             Some(Param::as_ref(ptr))
         }
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Param::Ref<'a>> {
+        if n >= self.remaining {
+            self.remaining = 0;
+            return None;
+        }
+        self.remaining -= n + 1;
+        unsafe { Some(Param::as_ref(Param::add(self.ptr, self.remaining))) }
+    }
 }
 
+// SAFE: Iter has the same semantics as core::slice::Iter.
+unsafe impl<'a, Param: ParallelParam + Sync> Send for Iter<'a, Param> {}
+unsafe impl<'a, Param: ParallelParam + Sync> Sync for Iter<'a, Param> {}
+
 /// An iterator over mutable reference to values in a [`ParallelSliceMut`].
 ///
 /// See [`ParallelSliceMut::iter_mut`].
@@ -75,6 +118,17 @@ impl<'a, Param: ParallelParam> Iterator for IterMut<'a, Param> {
             Some(output)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        unsafe { Some(Param::as_mut(Param::add(self.ptr, self.remaining - 1))) }
+    }
 }
 
 impl<'a, Param: ParallelParam> ExactSizeIterator for IterMut<'a, Param> {}
@@ -90,8 +144,117 @@ impl<'a, Param: ParallelParam> DoubleEndedIterator for IterMut<'a, Param> {
             Some(Param::as_mut(ptr))
         }
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Param::RefMut<'a>> {
+        if n >= self.remaining {
+            self.remaining = 0;
+            return None;
+        }
+        self.remaining -= n + 1;
+        unsafe { Some(Param::as_mut(Param::add(self.ptr, self.remaining))) }
+    }
+}
+
+// SAFE: IterMut has the same semantics as core::slice::IterMut.
+unsafe impl<'a, Param: ParallelParam + Send> Send for IterMut<'a, Param> {}
+unsafe impl<'a, Param: ParallelParam + Sync> Sync for IterMut<'a, Param> {}
+
+/// An iterator over mutable references to every `step`-th value in a
+/// [`ParallelSliceMut`].
+///
+/// See [`ParallelSliceMut::iter_mut_step_by`].
+///
+/// [`ParallelSliceMut`]: crate::ParallelSliceMut
+/// [`ParallelSliceMut::iter_mut_step_by`]: crate::ParallelSliceMut::iter_mut_step_by
+pub struct StepIterMut<'a, Param: ParallelParam> {
+    pub(crate) ptr: Param::Ptr,
+    pub(crate) remaining: usize,
+    pub(crate) step: usize,
+    pub(crate) _marker: PhantomData<&'a Param>,
+}
+
+impl<'a, Param: ParallelParam> Iterator for StepIterMut<'a, Param> {
+    type Item = Param::RefMut<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe {
+            if self.remaining == 0 {
+                return None;
+            }
+            let output = Param::as_mut(self.ptr);
+            self.remaining -= 1;
+            // Only advance if there is another element to yield, as stepping
+            // past the end of the allocation is undefined behavior.
+            if self.remaining != 0 {
+                self.ptr = Param::add(self.ptr, self.step);
+            }
+            Some(output)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
 }
 
+impl<'a, Param: ParallelParam> ExactSizeIterator for StepIterMut<'a, Param> {}
+
+// SAFE: StepIterMut has the same semantics as core::slice::IterMut.
+unsafe impl<'a, Param: ParallelParam + Send> Send for StepIterMut<'a, Param> {}
+unsafe impl<'a, Param: ParallelParam + Sync> Sync for StepIterMut<'a, Param> {}
+
+/// An iterator over a slice in (non-overlapping) chunks separated by a predicate.
+///
+/// See [`ParallelSlice::chunks_by`].
+///
+/// [`ParallelSlice::chunks_by`]: crate::ParallelSlice::chunks_by
+pub struct ChunkBy<'a, Param: ParallelParam, F> {
+    pub(crate) storage: Param::Storage,
+    pub(crate) len: usize,
+    pub(crate) predicate: F,
+    pub(crate) _marker: PhantomData<&'a Param>,
+}
+
+impl<'a, Param, F> Iterator for ChunkBy<'a, Param, F>
+where
+    Param: ParallelParam,
+    F: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> bool,
+{
+    type Item = ParallelSlice<'a, Param>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        unsafe {
+            let base = Param::as_ptr(self.storage);
+            let mut size = 1;
+            while size < self.len {
+                let prev = Param::as_ref(Param::add(base, size - 1));
+                let next = Param::as_ref(Param::add(base, size));
+                if !(self.predicate)(prev, next) {
+                    break;
+                }
+                size += 1;
+            }
+            let chunk = ParallelSlice::from_raw_parts(self.storage, size);
+            self.storage = Param::as_storage(Param::add(base, size));
+            self.len -= size;
+            Some(chunk)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.len == 0 {
+            (0, Some(0))
+        } else {
+            (1, Some(self.len))
+        }
+    }
+}
+
+// SAFE: ChunkBy has the same semantics as core::slice::ChunkBy.
+unsafe impl<'a, Param: ParallelParam + Sync, F: Send> Send for ChunkBy<'a, Param, F> {}
+unsafe impl<'a, Param: ParallelParam + Sync, F: Sync> Sync for ChunkBy<'a, Param, F> {}
+
 /// An iterator over values from a [`ParallelVec`].
 ///
 /// See [`ParallelVec::into_iter`].
@@ -106,6 +269,39 @@ pub struct IntoIter<Param: ParallelParam> {
     pub(crate) idx: usize,
 }
 
+impl<Param: ParallelParam> IntoIter<Param> {
+    /// Collects the elements that have not yet been yielded into a new
+    /// [`ParallelVec`], with a capacity that fits them exactly.
+    ///
+    /// If no elements have been yielded from the front, the iterator's storage is
+    /// reused. Otherwise, the remaining elements are copied into a new allocation.
+    pub fn into_parallel_vec(mut self) -> ParallelVec<Param> {
+        let remaining = self.len - self.idx;
+        if self.idx == 0 {
+            let mut vec = ParallelVec {
+                len: remaining,
+                storage: self.storage,
+                capacity: self.capacity,
+                allocator: Global,
+            };
+            // The storage and the remaining values are now owned by `vec`.
+            core::mem::forget(self);
+            vec.shrink_to_fit();
+            return vec;
+        }
+        let mut vec = ParallelVec::with_capacity(remaining);
+        unsafe {
+            let src = Param::ptr_at(self.storage, self.idx);
+            Param::copy_to_nonoverlapping(src, Param::as_ptr(vec.storage), remaining);
+            vec.len = remaining;
+        }
+        // The moved values are now owned by `vec`. Dropping `self` only frees
+        // the old storage.
+        self.idx = self.len;
+        vec
+    }
+}
+
 impl<Param: ParallelParam> Iterator for IntoIter<Param> {
     type Item = Param;
     fn next(&mut self) -> Option<Self::Item> {
@@ -113,7 +309,13 @@ impl<Param: ParallelParam> Iterator for IntoIter<Param> {
             if self.idx >= self.len {
                 return None;
             }
-            let ptr = Param::ptr_at(self.storage, self.idx);
+            // All-ZST rows are all stored at the dangling pointer, so skip
+            // computing an offset for them.
+            let ptr = if core::mem::size_of::<Param>() == 0 {
+                Param::as_ptr(self.storage)
+            } else {
+                Param::ptr_at(self.storage, self.idx)
+            };
             let value = Param::read(ptr);
             self.idx += 1;
             Some(value)
@@ -131,11 +333,16 @@ impl<Param: ParallelParam> ExactSizeIterator for IntoIter<Param> {}
 impl<Param: ParallelParam> DoubleEndedIterator for IntoIter<Param> {
     fn next_back(&mut self) -> Option<Param> {
         unsafe {
-            if self.len == 0 {
+            // Values before `idx` have already been moved out from the front.
+            if self.idx >= self.len {
                 return None;
             }
             self.len -= 1;
-            let ptr = Param::ptr_at(self.storage, self.len);
+            let ptr = if core::mem::size_of::<Param>() == 0 {
+                Param::as_ptr(self.storage)
+            } else {
+                Param::ptr_at(self.storage, self.len)
+            };
             Some(Param::read(ptr))
         }
     }
@@ -145,10 +352,90 @@ impl<Param: ParallelParam> Drop for IntoIter<Param> {
     fn drop(&mut self) {
         unsafe {
             // Drop the unconsumed items.
-            for idx in self.idx..self.len {
-                Param::drop(Param::ptr_at(self.storage, idx));
+            Param::drop_range(Param::ptr_at(self.storage, self.idx), self.len - self.idx);
+            Param::dealloc(self.storage, self.capacity, &Global);
+        }
+    }
+}
+
+// SAFE: IntoIter owns the remaining values and the allocation, just like
+// alloc::vec::IntoIter.
+unsafe impl<Param: ParallelParam + Send> Send for IntoIter<Param> {}
+unsafe impl<Param: ParallelParam + Sync> Sync for IntoIter<Param> {}
+
+/// A draining iterator for [`ParallelVec`].
+///
+/// See [`ParallelVec::drain`].
+///
+/// When dropped, every element in the drained range that was not yielded is
+/// dropped, and the elements after the range are moved back to close the gap,
+/// just like [`alloc::vec::Drain`].
+///
+/// [`ParallelVec`]: crate::ParallelVec
+/// [`ParallelVec::drain`]: crate::ParallelVec::drain
+pub struct Drain<'a, Param: ParallelParam, A: Allocator = Global> {
+    pub(crate) vec: &'a mut ParallelVec<Param, A>,
+    pub(crate) idx: usize,
+    pub(crate) end: usize,
+    pub(crate) tail_start: usize,
+    pub(crate) tail_len: usize,
+}
+
+impl<'a, Param: ParallelParam, A: Allocator> Iterator for Drain<'a, Param, A> {
+    type Item = Param;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx >= self.end {
+            return None;
+        }
+        unsafe {
+            let value = Param::read(Param::ptr_at(self.vec.storage, self.idx));
+            self.idx += 1;
+            Some(value)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.idx;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, Param: ParallelParam, A: Allocator> ExactSizeIterator for Drain<'a, Param, A> {}
+
+impl<'a, Param: ParallelParam, A: Allocator> DoubleEndedIterator for Drain<'a, Param, A> {
+    fn next_back(&mut self) -> Option<Param> {
+        if self.idx >= self.end {
+            return None;
+        }
+        unsafe {
+            self.end -= 1;
+            Some(Param::read(Param::ptr_at(self.vec.storage, self.end)))
+        }
+    }
+}
+
+impl<'a, Param: ParallelParam, A: Allocator> Drop for Drain<'a, Param, A> {
+    fn drop(&mut self) {
+        unsafe {
+            // Drop the rows that were not yielded. If one of these panics, the
+            // tail is leaked instead of being moved back, which is safe.
+            let ptr = Param::ptr_at(self.vec.storage, self.idx);
+            let remaining = self.end - self.idx;
+            self.idx = self.end;
+            Param::drop_range(ptr, remaining);
+            // Move the tail back to fill the drained hole.
+            let start = self.vec.len;
+            if self.tail_len > 0 && self.tail_start != start {
+                let src = Param::ptr_at(self.vec.storage, self.tail_start);
+                let dst = Param::ptr_at(self.vec.storage, start);
+                Param::copy_to(src, dst, self.tail_len);
             }
-            Param::dealloc(self.storage, self.capacity);
+            self.vec.len = start + self.tail_len;
         }
     }
 }
+
+// SAFE: Drain owns the drained values and mutably borrows the vec, just
+// like alloc::vec::Drain.
+unsafe impl<'a, Param: ParallelParam + Send, A: Allocator + Send> Send for Drain<'a, Param, A> {}
+unsafe impl<'a, Param: ParallelParam + Sync, A: Allocator + Sync> Sync for Drain<'a, Param, A> {}
@@ -55,27 +55,48 @@
 //!  ## `serde` Support
 //! `ParallelVec` can be serialized if it's parameters can be serialized. This is disabled by
 //! default. Use the `serde` feature to enable support for serialization and deserialization.
+//!
+//! ## `arbitrary` Support
+//! `ParallelVec` implements [`Arbitrary`] if it's parameters implement it, for use in fuzzing.
+//! This is disabled by default. Use the `arbitrary` feature to enable it.
+//!
+//! ## `rayon` Support
+//! Enabling the `rayon` feature adds parallel sorting methods, such as
+//! [`ParallelSliceMut::par_sort_by`], which run the comparisons on the [`rayon`] thread pool.
+//!
+//! [`Arbitrary`]: https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html
+//! [`rayon`]: https://docs.rs/rayon
 
 extern crate alloc;
 
+use core::alloc::Layout;
+use core::ops::{Bound, Range, RangeBounds};
+
 #[cfg(any(test, feature = "std"))]
 #[macro_use]
 extern crate std;
 
+mod allocator;
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+mod arbitrary;
+/// Entry types for [`ParallelVec::entry_by_key`].
+pub mod entry;
 /// A collection of iterators types for [`ParallelVec`].
 pub mod iter;
 /// Implementations for [`ParallelParam`].
 pub mod param;
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+mod rayon;
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 mod serde;
 mod slice;
 mod vec;
 
-#[cfg(feature = "serde")]
-pub use crate::serde::*;
-
-pub use param::ParallelParam;
+pub use allocator::{Allocator, Global};
+pub use param::{ParallelColumn, ParallelParam};
 pub use slice::{ParallelSlice, ParallelSliceMut};
 pub use vec::ParallelVec;
 
@@ -86,6 +107,20 @@ pub enum ParallelVecConversionError {
     UnevenLengths,
 }
 
+/// Error when attempting to reserve capacity for a [`ParallelVec`].
+///
+/// See [`ParallelVec::try_reserve`].
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub enum TryReserveError {
+    /// The requested capacity exceeds the maximum size of an allocation.
+    CapacityOverflow,
+    /// The allocator returned an error.
+    AllocError {
+        /// The layout of the allocation request that failed.
+        layout: Layout,
+    },
+}
+
 #[inline(always)]
 pub(crate) fn assert_in_bounds(idx: usize, len: usize) {
     assert!(idx < len, "Index out of bounds: {} (len: {})", idx, len);
@@ -96,7 +131,35 @@ pub(crate) fn assert_in_bounds_inclusive(idx: usize, len: usize) {
     assert!(idx <= len, "Index out of bounds: {} (len: {})", idx, len);
 }
 
+/// Converts any range into a `Range<usize>`, checking that it is in bounds
+/// of a slice of length `len`.
+#[inline]
+pub(crate) fn to_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start
+            .checked_add(1)
+            .unwrap_or_else(|| panic!("attempted to index slice from after maximum usize")),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end
+            .checked_add(1)
+            .unwrap_or_else(|| panic!("attempted to index slice up to maximum usize")),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    assert!(
+        start <= end,
+        "slice index starts at {} but ends at {}",
+        start,
+        end
+    );
+    assert_in_bounds_inclusive(end, len);
+    start..end
+}
+
 #[inline(always)]
-pub(crate) fn out_of_bounds(idx: usize, len: usize) {
+pub(crate) fn out_of_bounds(idx: usize, len: usize) -> ! {
     panic!("Index out of bounds: {} (len: {})", idx, len);
 }
@@ -1,6 +1,7 @@
-use super::{ParallelVec, ParallelVecConversionError};
+use super::{Allocator, ParallelVec, ParallelVecConversionError, TryReserveError};
 use alloc::{
-    alloc::{alloc, dealloc, handle_alloc_error, realloc, Layout},
+    alloc::{handle_alloc_error, Layout},
+    boxed::Box,
     vec::Vec,
 };
 use core::ptr::NonNull;
@@ -30,15 +31,29 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
     type RefMut<'a>;
     /// A set of [`Vec<T>`]s of the parameter.
     type Vecs;
+    /// A set of [`Box<[T]>`]s of the parameter.
+    type BoxedSlices;
     /// A set of mutable slice references of the parameter.
     type Slices<'a>;
     /// A set of mutable slice references of the parameter.
     type SlicesMut<'a>;
+    /// A set of immutable fixed-size array references of the parameter.
+    type Arrays<'a, const N: usize>;
     /// A set of iterators of immutable references of the parameter.
     type Iters<'a>;
     /// A set of iterators of mutable references of the parameter.
     type ItersMut<'a>;
 
+    /// The number of fields in the parameter.
+    const FIELD_COUNT: usize;
+
+    /// The number of bytes one element occupies across all of its columns.
+    ///
+    /// This is the sum of the sizes of the fields. Unlike the size of the tuple,
+    /// it does not include any padding between fields, as each field is stored
+    /// in its own column.
+    const ROW_SIZE: usize;
+
     /// Creates a set of dangling pointers for the given types.
     fn dangling() -> Self::Storage;
 
@@ -46,33 +61,48 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
     /// pointer types.
     fn as_ptr(storage: Self::Storage) -> Self::Ptr;
 
-    /// Allocates a buffer for a given capacity.
+    /// Allocates a buffer for a given capacity from `allocator`.
+    ///
+    /// # Safety
+    /// Capacity should be non-zero.
+    unsafe fn alloc<A: Allocator>(capacity: usize, allocator: &A) -> Self::Storage;
+
+    /// Allocates a buffer for a given capacity, returning an error instead of
+    /// aborting if the allocation fails.
+    ///
+    /// If allocating any of the fields fails, every field that was successfully
+    /// allocated is freed before returning.
     ///
     /// # Safety
     /// Capacity should be non-zero.
-    unsafe fn alloc(capacity: usize) -> Self::Storage;
+    unsafe fn try_alloc<A: Allocator>(
+        capacity: usize,
+        allocator: &A,
+    ) -> Result<Self::Storage, TryReserveError>;
 
     /// Realloc a buffer allocated from [`alloc`].
     ///
     /// # Safety
     /// `storage` must have been allocated from [`alloc`] or [`realloc`] alongside
-    /// the provided `current_capacity`.
+    /// the provided `current_capacity`, using the same `allocator`.
     ///
     /// [`alloc`]: Self::alloc
-    unsafe fn realloc(
+    /// [`realloc`]: Self::realloc
+    unsafe fn realloc<A: Allocator>(
         storage: Self::Storage,
         current_capacity: usize,
         new_capacity: usize,
+        allocator: &A,
     ) -> Self::Storage;
 
     /// Deallocates a buffer allocated from [`alloc`].
     ///
     /// # Safety
     /// `storage` must have been allocated from [`alloc`] alongside
-    /// the provided `capacity`.
+    /// the provided `capacity`, using the same `allocator`.
     ///
     /// [`alloc`]: Self::alloc
-    unsafe fn dealloc(storage: Self::Storage, capacity: usize);
+    unsafe fn dealloc<A: Allocator>(storage: Self::Storage, capacity: usize, allocator: &A);
 
     /// Gets the pointer at a given index.
     ///
@@ -91,6 +121,12 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
     /// length.
     fn get_vec_len(vecs: &Self::Vecs) -> Option<usize>;
 
+    /// Gets the length for the provided slices.
+    ///
+    /// Returns `None` if not all of the slices share the same
+    /// length.
+    fn get_slices_len(slices: &Self::Slices<'_>) -> Option<usize>;
+
     /// Gets the underlying pointers for the associated `Vec`s.
     ///
     /// # Safety
@@ -137,6 +173,23 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
     /// for the allocation that `ptr` points to.
     unsafe fn as_slices_mut<'a>(ptr: Self::Ptr, len: usize) -> Self::SlicesMut<'a>;
 
+    /// Creates a set of boxed slices from `ptr` and a provided length, taking ownership
+    /// of the allocation.
+    ///
+    /// # Safety
+    /// `ptr` must have been allocated from [`alloc`] or [`realloc`] with a capacity
+    /// of exactly `len`, or be dangling if `len` is zero, and must point to `len`
+    /// initialized values. The allocation must not be used after this call.
+    ///
+    /// [`alloc`]: Self::alloc
+    /// [`realloc`]: Self::realloc
+    unsafe fn into_boxed_slices(ptr: Self::Ptr, len: usize) -> Self::BoxedSlices;
+
+    /// Converts a set of immutable slices into fixed-size array references.
+    ///
+    /// Returns `None` if the slices are not exactly `N` elements long.
+    fn as_arrays<const N: usize>(slices: Self::Slices<'_>) -> Option<Self::Arrays<'_, N>>;
+
     /// Creates a set of iterators from slices.
     #[allow(clippy::needless_lifetimes)]
     fn iters<'a>(slices: Self::Slices<'a>) -> Self::Iters<'a>;
@@ -154,6 +207,10 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
     /// `ptr` must be a valid, non-null pointer.
     unsafe fn as_ref<'a>(ptr: Self::Ptr) -> Self::Ref<'a>;
 
+    /// Converts a reference to a value into a set of immutable references
+    /// to each of its fields.
+    fn as_refs(value: &Self) -> Self::Ref<'_>;
+
     /// Converts `ptr` into the storage type.
     ///
     /// # Safety
@@ -180,6 +237,8 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
 
     /// Swaps the values pointed to by the provided pointers.
     ///
+    /// `a` and `b` may be equal, in which case this is a no-op.
+    ///
     /// # Safety
     /// Both `a` and `b` must be valid for all of it's consitutent member pointers.
     unsafe fn swap(a: Self::Ptr, other: Self::Ptr);
@@ -190,6 +249,36 @@ pub unsafe trait ParallelParam: Sized + private::Sealed {
     /// The caller must ensure that the values pointed to by the pointers have
     /// not already been dropped prior.
     unsafe fn drop(ptr: Self::Ptr);
+
+    /// Drops `len` consecutive values starting at `ptr`.
+    ///
+    /// The values are dropped one field at a time: all of the values of the first
+    /// field are dropped in order, then all of the values of the second field, and
+    /// so on.
+    ///
+    /// # Safety
+    /// The caller must ensure that `ptr..ptr + len` is valid for all of it's
+    /// constituent member pointers, and that the values pointed to have not
+    /// already been dropped prior.
+    unsafe fn drop_range(ptr: Self::Ptr, len: usize);
+}
+
+/// Provides access to the `I`-th field of a [`ParallelParam`], which is stored
+/// as its own contiguous column.
+///
+/// This trait has blanket implementations for every field of all tuples of up
+/// to size 12 of all types that are `'static`.
+///
+/// # Safety
+/// [`column_ptr`] must return the pointer to the `I`-th field of the provided pointers.
+///
+/// [`column_ptr`]: Self::column_ptr
+pub unsafe trait ParallelColumn<const I: usize>: ParallelParam {
+    /// The type of the `I`-th field of the parameter.
+    type Column;
+
+    /// Gets the pointer to the `I`-th field from a set of pointers.
+    fn column_ptr(ptr: Self::Ptr) -> *mut Self::Column;
 }
 
 mod private {
@@ -220,6 +309,38 @@ macro_rules! skip_first {
     };
 }
 
+macro_rules! count_one {
+    ($t:ident) => {
+        1
+    };
+}
+
+/// Allocates an array with the given layout, returning a dangling pointer for
+/// zero-sized types, and `None` if the allocation fails.
+///
+/// # Safety
+/// `layout` must be a valid array layout for `T`.
+unsafe fn try_alloc_array<T, A: Allocator>(layout: Layout, allocator: &A) -> Option<NonNull<T>> {
+    if core::mem::size_of::<T>() == 0 {
+        Some(NonNull::dangling())
+    } else {
+        allocator.allocate(layout).map(NonNull::cast::<T>)
+    }
+}
+
+/// Frees an array allocated by [`try_alloc_array`], if it was allocated.
+///
+/// # Safety
+/// `ptr` must have been returned by [`try_alloc_array`] with the same `layout`
+/// and `allocator`.
+unsafe fn free_array<T, A: Allocator>(ptr: Option<NonNull<T>>, layout: Layout, allocator: &A) {
+    if let Some(ptr) = ptr {
+        if core::mem::size_of::<T>() != 0 {
+            allocator.deallocate(ptr.cast::<u8>(), layout);
+        }
+    }
+}
+
 macro_rules! impl_parallel_vec_param {
     ($t1: ident, $v1: ident, $($ts:ident, $vs:ident),*) => {
         unsafe impl<$t1: 'static $(, $ts: 'static)*> ParallelParam for ($t1 $(, $ts)*) {
@@ -229,11 +350,16 @@ macro_rules! impl_parallel_vec_param {
             type Slices<'a> = (&'a [$t1] $(, &'a [$ts])*);
             type SlicesMut<'a> = (&'a mut [$t1] $(, &'a mut [$ts])*);
             type Vecs = (Vec<$t1> $(, Vec<$ts>)*);
+            type BoxedSlices = (Box<[$t1]> $(, Box<[$ts]>)*);
             type Ptr = (*mut $t1 $(, *mut $ts)*);
             type Offsets = (usize $(, skip_first!($ts, usize))*);
+            type Arrays<'a, const N: usize> = (&'a [$t1; N] $(, &'a [$ts; N])*);
             type Iters<'a> = (core::slice::Iter<'a, $t1> $(, core::slice::Iter<'a, $ts>)*);
             type ItersMut<'a>= (core::slice::IterMut<'a, $t1> $(, core::slice::IterMut<'a, $ts>)*);
 
+            const FIELD_COUNT: usize = 1 $(+ count_one!($ts))*;
+            const ROW_SIZE: usize = core::mem::size_of::<$t1>() $(+ core::mem::size_of::<$ts>())*;
+
             #[inline(always)]
             fn dangling() -> Self::Storage {
                 (NonNull::dangling(), $(NonNull::<$ts>::dangling()),*)
@@ -245,20 +371,20 @@ macro_rules! impl_parallel_vec_param {
                 ($t1.as_ptr() $(, $ts.as_ptr())*)
             }
 
-            unsafe fn alloc(capacity: usize) -> Self::Storage {
+            unsafe fn alloc<A: Allocator>(capacity: usize, allocator: &A) -> Self::Storage {
                 debug_assert!(capacity != 0);
                 let $t1 = if core::mem::size_of::<$t1>() != 0 {
                     let layout = Layout::array::<$t1>(capacity).unwrap();
-                    let ptr = alloc(layout).cast::<$t1>();
-                    NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(layout))
+                    let ptr = allocator.allocate(layout).unwrap_or_else(|| handle_alloc_error(layout));
+                    ptr.cast::<$t1>()
                 } else {
                     NonNull::dangling()
                 };
                 $(
                     let $ts = if core::mem::size_of::<$ts>() != 0 {
                         let layout = Layout::array::<$ts>(capacity).unwrap();
-                        let ptr = alloc(layout).cast::<$ts>();
-                        NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(layout))
+                        let ptr = allocator.allocate(layout).unwrap_or_else(|| handle_alloc_error(layout));
+                        ptr.cast::<$ts>()
                     } else {
                         NonNull::dangling()
                     };
@@ -266,29 +392,49 @@ macro_rules! impl_parallel_vec_param {
                 ($t1 $(, $ts)*)
             }
 
-            unsafe fn realloc(storage: Self::Storage, current_capacity: usize, new_capacity: usize) -> Self::Storage {
+            unsafe fn try_alloc<A: Allocator>(capacity: usize, allocator: &A) -> Result<Self::Storage, TryReserveError> {
+                debug_assert!(capacity != 0);
+                let $t1 = Layout::array::<$t1>(capacity).map_err(|_| TryReserveError::CapacityOverflow)?;
+                $(
+                    let $ts = Layout::array::<$ts>(capacity).map_err(|_| TryReserveError::CapacityOverflow)?;
+                )*
+                let $v1 = try_alloc_array::<$t1, A>($t1, allocator);
+                $(let $vs = try_alloc_array::<$ts, A>($ts, allocator);)*
+                if let (Some($v1), $(Some($vs)),*) = ($v1, $($vs),*) {
+                    return Ok(($v1, $($vs),*));
+                }
+                // Free the fields that were allocated, and report the first failure.
+                free_array($v1, $t1, allocator);
+                $(free_array($vs, $ts, allocator);)*
+                let failed = [$v1.is_none() $(, $vs.is_none())*];
+                let layouts = [$t1 $(, $ts)*];
+                let idx = failed.iter().position(|failed| *failed).unwrap_or(0);
+                Err(TryReserveError::AllocError { layout: layouts[idx] })
+            }
+
+            unsafe fn realloc<A: Allocator>(storage: Self::Storage, current_capacity: usize, new_capacity: usize, allocator: &A) -> Self::Storage {
                 if new_capacity == 0 {
-                    Self::dealloc(storage, current_capacity);
+                    Self::dealloc(storage, current_capacity, allocator);
                     return Self::dangling();
                 }
                 if current_capacity == 0 {
-                    return Self::alloc(new_capacity);
+                    return Self::alloc(new_capacity, allocator);
                 }
                 let ($t1 $(, $ts)*) = storage;
                 let $t1 = if core::mem::size_of::<$t1>() != 0 {
                     let layout = Layout::array::<$t1>(current_capacity).unwrap();
-                    let new_size = core::mem::size_of::<$t1>().checked_mul(new_capacity).unwrap();
-                    let ptr = realloc($t1.as_ptr().cast::<u8>(), layout, new_size).cast::<$t1>();
-                    NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(layout))
+                    let new_layout = Layout::array::<$t1>(new_capacity).unwrap();
+                    let ptr = allocator.reallocate($t1.cast::<u8>(), layout, new_layout);
+                    ptr.unwrap_or_else(|| handle_alloc_error(new_layout)).cast::<$t1>()
                 } else {
                     $t1
                 };
                 $(
                     let $ts = if core::mem::size_of::<$ts>() != 0 {
                         let layout = Layout::array::<$ts>(current_capacity).unwrap();
-                        let new_size = core::mem::size_of::<$ts>().checked_mul(new_capacity).unwrap();
-                        let ptr = realloc($ts.as_ptr().cast::<u8>(), layout, new_size).cast::<$ts>();
-                        NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(layout))
+                        let new_layout = Layout::array::<$ts>(new_capacity).unwrap();
+                        let ptr = allocator.reallocate($ts.cast::<u8>(), layout, new_layout);
+                        ptr.unwrap_or_else(|| handle_alloc_error(new_layout)).cast::<$ts>()
                     } else {
                         $ts
                     };
@@ -296,17 +442,17 @@ macro_rules! impl_parallel_vec_param {
                 ($t1 $(, $ts)*)
             }
 
-            unsafe fn dealloc(storage: Self::Storage, capacity: usize) {
+            unsafe fn dealloc<A: Allocator>(storage: Self::Storage, capacity: usize, allocator: &A) {
                 if capacity == 0 {
                     return;
                 }
                 let ($t1 $(, $ts)*) = storage;
                 if core::mem::size_of::<$t1>() != 0 {
-                    dealloc($t1.as_ptr().cast::<u8>(), Layout::array::<$t1>(capacity).unwrap_unchecked());
+                    allocator.deallocate($t1.cast::<u8>(), Layout::array::<$t1>(capacity).unwrap_unchecked());
                 }
                 $(
                     if core::mem::size_of::<$ts>() != 0 {
-                        dealloc($ts.as_ptr().cast::<u8>(), Layout::array::<$ts>(capacity).unwrap_unchecked());
+                        allocator.deallocate($ts.cast::<u8>(), Layout::array::<$ts>(capacity).unwrap_unchecked());
                     }
                 )*
             }
@@ -357,6 +503,23 @@ macro_rules! impl_parallel_vec_param {
                 )
             }
 
+            #[inline(always)]
+            unsafe fn into_boxed_slices(ptr: Self::Ptr, len: usize) -> Self::BoxedSlices {
+                let ($t1, $($ts),*) = ptr;
+                (
+                    Box::from_raw(core::ptr::slice_from_raw_parts_mut($t1, len))
+                    $(
+                        , Box::from_raw(core::ptr::slice_from_raw_parts_mut($ts, len))
+                    )*
+                )
+            }
+
+            #[inline(always)]
+            fn as_arrays<const N: usize>(slices: Self::Slices<'_>) -> Option<Self::Arrays<'_, N>> {
+                let ($t1, $($ts),*) = slices;
+                Some(($t1.try_into().ok()? $(, $ts.try_into().ok()?)*))
+            }
+
             #[inline(always)]
             fn iters<'a>(slices: Self::Slices<'a>) -> Self::Iters<'a> {
                 let ($t1, $($ts),*) = slices;
@@ -391,6 +554,12 @@ macro_rules! impl_parallel_vec_param {
                 (&*$t1 $(, &*$ts)*)
             }
 
+            #[inline(always)]
+            fn as_refs(value: &Self) -> Self::Ref<'_> {
+                let ($t1, $($ts),*) = value;
+                ($t1 $(, $ts)*)
+            }
+
             #[inline(always)]
             unsafe fn as_mut<'a>(ptr: Self::Ptr) -> Self::RefMut<'a> {
                 let ($t1, $($ts),*) = ptr;
@@ -415,8 +584,16 @@ macro_rules! impl_parallel_vec_param {
             unsafe fn swap(a: Self::Ptr, b: Self::Ptr) {
                 let ($v1, $($vs),*) = a;
                 let ($t1, $($ts),*) = b;
-                core::ptr::swap($t1, $v1);
-                $(core::ptr::swap($ts, $vs);)*
+                // Swapping an element with itself must be allowed, which
+                // `ptr::swap_nonoverlapping` does not support, so it is skipped.
+                if $t1 != $v1 {
+                    core::ptr::swap_nonoverlapping($t1, $v1, 1);
+                }
+                $(
+                    if $ts != $vs {
+                        core::ptr::swap_nonoverlapping($ts, $vs, 1);
+                    }
+                )*
             }
 
             #[inline(always)]
@@ -426,6 +603,13 @@ macro_rules! impl_parallel_vec_param {
                 $(core::ptr::drop_in_place($ts);)*
             }
 
+            #[inline(always)]
+            unsafe fn drop_range(ptr: Self::Ptr, len: usize) {
+                let ($t1, $($ts),*) = ptr;
+                core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut($t1, len));
+                $(core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut($ts, len));)*
+            }
+
             fn get_vec_len(vecs: &Self::Vecs) -> Option<usize> {
                 let ($t1, $($ts),*) = vecs;
                 let len = $t1.len();
@@ -437,12 +621,34 @@ macro_rules! impl_parallel_vec_param {
                 Some(len)
             }
 
+            fn get_slices_len(slices: &Self::Slices<'_>) -> Option<usize> {
+                let ($t1, $($ts),*) = slices;
+                let len = $t1.len();
+                $(
+                    if $ts.len() != len {
+                        return None;
+                    }
+                )*
+                Some(len)
+            }
+
             unsafe fn get_vec_ptrs(vecs: &mut Self::Vecs) -> Self::Ptr {
                 let ($t1, $($ts),*) = vecs;
                 ($t1.as_mut_ptr() $(, $ts.as_mut_ptr())*)
             }
         }
 
+        impl<$t1: Clone + 'static $(, $ts: Clone + 'static)*> ParallelVec<($t1 $(, $ts)*)> {
+            /// Clones each field of the vector into its own [`Vec`].
+            ///
+            /// This leaves the vector untouched. The returned `Vec`s all have the
+            /// same length as the vector.
+            pub fn collect_columns(&self) -> (Vec<$t1> $(, Vec<$ts>)*) {
+                let ($t1, $($ts),*) = self.as_slices();
+                ($t1.to_vec() $(, $ts.to_vec())*)
+            }
+        }
+
         impl<$t1: 'static $(, $ts: 'static)*> TryFrom<(Vec<$t1> $(, Vec<$ts>)*)> for ParallelVec<($t1 $(, $ts)*)> {
             type Error = ParallelVecConversionError;
             fn try_from(mut vecs: (Vec<$t1> $(, Vec<$ts>)*)) -> Result<Self, Self::Error> {
@@ -465,6 +671,22 @@ macro_rules! impl_parallel_vec_param {
     }
 }
 
+macro_rules! impl_parallel_column {
+    (@column [$($ts:ident),*] $idx:tt $t:ident) => {
+        unsafe impl<$($ts: 'static),*> ParallelColumn<$idx> for ($($ts,)*) {
+            type Column = $t;
+
+            #[inline(always)]
+            fn column_ptr(ptr: Self::Ptr) -> *mut $t {
+                ptr.$idx
+            }
+        }
+    };
+    ($ts:tt; $($idx:tt => $t:ident),*) => {
+        $(impl_parallel_column!(@column $ts $idx $t);)*
+    };
+}
+
 impl_parallel_vec_param!(T1, V1, T2, V2);
 impl_parallel_vec_param!(T1, V1, T2, V2, T3, V3);
 impl_parallel_vec_param!(T1, V1, T2, V2, T3, V3, T4, V4);
@@ -483,3 +705,38 @@ impl_parallel_vec_param!(
     T1, V1, T2, V2, T3, T4, V3, V4, T5, V5, T6, V6, T7, V7, T8, V8, T9, V9, T10, V10, T11, V11,
     T12, V12
 );
+
+impl_parallel_column!([T1, T2]; 0 => T1, 1 => T2);
+impl_parallel_column!([T1, T2, T3]; 0 => T1, 1 => T2, 2 => T3);
+impl_parallel_column!([T1, T2, T3, T4]; 0 => T1, 1 => T2, 2 => T3, 3 => T4);
+impl_parallel_column!([T1, T2, T3, T4, T5]; 0 => T1, 1 => T2, 2 => T3, 3 => T4, 4 => T5);
+impl_parallel_column!(
+    [T1, T2, T3, T4, T5, T6];
+    0 => T1, 1 => T2, 2 => T3, 3 => T4, 4 => T5, 5 => T6
+);
+impl_parallel_column!(
+    [T1, T2, T3, T4, T5, T6, T7];
+    0 => T1, 1 => T2, 2 => T3, 3 => T4, 4 => T5, 5 => T6, 6 => T7
+);
+impl_parallel_column!(
+    [T1, T2, T3, T4, T5, T6, T7, T8];
+    0 => T1, 1 => T2, 2 => T3, 3 => T4, 4 => T5, 5 => T6, 6 => T7, 7 => T8
+);
+impl_parallel_column!(
+    [T1, T2, T3, T4, T5, T6, T7, T8, T9];
+    0 => T1, 1 => T2, 2 => T3, 3 => T4, 4 => T5, 5 => T6, 6 => T7, 7 => T8, 8 => T9
+);
+impl_parallel_column!(
+    [T1, T2, T3, T4, T5, T6, T7, T8, T9, T10];
+    0 => T1, 1 => T2, 2 => T3, 3 => T4, 4 => T5, 5 => T6, 6 => T7, 7 => T8, 8 => T9, 9 => T10
+);
+impl_parallel_column!(
+    [T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11];
+    0 => T1, 1 => T2, 2 => T3, 3 => T4, 4 => T5, 5 => T6, 6 => T7, 7 => T8, 8 => T9, 9 => T10,
+    10 => T11
+);
+impl_parallel_column!(
+    [T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12];
+    0 => T1, 1 => T2, 2 => T3, 3 => T4, 4 => T5, 5 => T6, 6 => T7, 7 => T8, 8 => T9, 9 => T10,
+    10 => T11, 11 => T12
+);
@@ -0,0 +1,57 @@
+use crate::{ParallelParam, ParallelSlice, ParallelSliceMut};
+use core::cmp::Ordering;
+use rayon::slice::ParallelSliceMut as _;
+
+impl<'a, Param: ParallelParam + Sync> ParallelSliceMut<'a, Param> {
+    /// Sorts the slice in parallel with a comparator function.
+    ///
+    /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate sorting
+    /// buffer. The comparisons are run in parallel on the [`rayon`] thread pool, and the
+    /// resulting permutation is then applied to the slice sequentially.
+    ///
+    /// This defers to [`rayon::slice::ParallelSliceMut::par_sort_by`], so any properties it
+    /// has will also hold for this function. Like [`sort_by`], this sort is stable.
+    ///
+    /// [`rayon`]: https://docs.rs/rayon
+    /// [`rayon::slice::ParallelSliceMut::par_sort_by`]: https://docs.rs/rayon/latest/rayon/slice/trait.ParallelSliceMut.html#method.par_sort_by
+    /// [`sort_by`]: Self::sort_by
+    pub fn par_sort_by<F>(&mut self, f: F)
+    where
+        F: Fn(Param::Ref<'_>, Param::Ref<'_>) -> Ordering + Sync,
+    {
+        // SAFE: The view is only read from while sorting the indices, before
+        // any element in the slice is moved.
+        let view = unsafe {
+            let storage = Param::as_storage(self.as_mut_ptrs());
+            ParallelSlice::<Param>::from_raw_parts(storage, self.len())
+        };
+        self.sort_via(|indices| {
+            indices
+                .par_sort_by(|a, b| unsafe { f(view.get_unchecked(*a), view.get_unchecked(*b)) });
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ParallelVec;
+    use std::vec::Vec;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_par_sort_by_matches_sort_by() {
+        let mut state = 0x2545_f491_u32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        };
+        let rows: Vec<(u32, u64)> = (0..100_000).map(|i| (next() % 1000, i)).collect();
+        let mut sequential: ParallelVec<(u32, u64)> = rows.iter().copied().collect();
+        let mut parallel: ParallelVec<(u32, u64)> = rows.into_iter().collect();
+        sequential.sort_by(|(a, _), (b, _)| a.cmp(b));
+        parallel.par_sort_by(|(a, _), (b, _)| a.cmp(b));
+        assert_eq!(parallel, sequential);
+    }
+}
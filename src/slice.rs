@@ -1,12 +1,14 @@
-use crate::iter::{Iter, IterMut};
-use crate::ParallelParam;
-use crate::{assert_in_bounds, assert_in_bounds_inclusive};
+use crate::iter::{ChunkBy, Iter, IterMut, StepIterMut};
+use crate::{assert_in_bounds, assert_in_bounds_inclusive, out_of_bounds, to_range};
+use crate::{ParallelColumn, ParallelParam};
 use alloc::vec::Vec;
 use core::{
     cmp::Ordering,
     hash::{Hash, Hasher},
+    iter::{Sum, Zip},
     marker::PhantomData,
-    ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo},
+    mem::ManuallyDrop,
+    ops::{Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
 };
 
 /// A immutable dynamically-sized view into a contiguous heterogeneous sequence.
@@ -93,6 +95,73 @@ impl<'a, Param: ParallelParam> ParallelSlice<'a, Param> {
         }
     }
 
+    /// Returns the first and all the rest of the elements of the slice, or `None` if it is empty.
+    pub fn split_first(&self) -> Option<(Param::Ref<'_>, ParallelSlice<'_, Param>)> {
+        if self.len == 0 {
+            return None;
+        }
+        unsafe {
+            let first = Param::as_ref(Param::as_ptr(self.storage));
+            let rest = Param::as_storage(Param::ptr_at(self.storage, 1));
+            Some((first, ParallelSlice::from_raw_parts(rest, self.len - 1)))
+        }
+    }
+
+    /// Returns the last and all the rest of the elements of the slice, or `None` if it is empty.
+    pub fn split_last(&self) -> Option<(Param::Ref<'_>, ParallelSlice<'_, Param>)> {
+        if self.len == 0 {
+            return None;
+        }
+        unsafe {
+            let last = Param::as_ref(Param::ptr_at(self.storage, self.len - 1));
+            Some((
+                last,
+                ParallelSlice::from_raw_parts(self.storage, self.len - 1),
+            ))
+        }
+    }
+
+    /// Divides the slice into two at an index.
+    ///
+    /// The first will contain all indices from `[0, mid)` (excluding the index `mid`
+    /// itself) and the second will contain all indices from `[mid, len)` (excluding
+    /// the index `len` itself).
+    ///
+    /// # Panics
+    /// This function will panic if `mid > len`.
+    pub fn split_at(&self, mid: usize) -> (ParallelSlice<'_, Param>, ParallelSlice<'_, Param>) {
+        assert_in_bounds_inclusive(mid, self.len);
+        unsafe {
+            let rest = Param::as_storage(Param::ptr_at(self.storage, mid));
+            (
+                ParallelSlice::from_raw_parts(self.storage, mid),
+                ParallelSlice::from_raw_parts(rest, self.len - mid),
+            )
+        }
+    }
+
+    /// Gets an immutable view of the elements in `range`.
+    ///
+    /// This is the equivalent of `&slice[range]` for `[T]`. [`Index`] cannot be
+    /// implemented to return a [`ParallelSlice`], as [`Index::index`] must return a
+    /// reference to a value stored within `self`, and a [`ParallelSlice`] is a view
+    /// constructed on demand.
+    ///
+    /// # Panics
+    /// This function will panic if the start of the range is greater than its end,
+    /// or if the end of the range is greater than `self.len`.
+    ///
+    /// [`Index`]: core::ops::Index
+    /// [`Index::index`]: core::ops::Index::index
+    #[inline]
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> ParallelSlice<'_, Param> {
+        let range = to_range(range, self.len);
+        unsafe {
+            let ptr = Param::ptr_at(self.storage, range.start);
+            ParallelSlice::from_raw_parts(Param::as_storage(ptr), range.len())
+        }
+    }
+
     /// Gets a immutable reference to the elements at `index`.
     ///
     /// # Panics
@@ -118,12 +187,118 @@ impl<'a, Param: ParallelParam> ParallelSlice<'a, Param> {
         Param::as_ref(Param::ptr_at(self.storage, index))
     }
 
+    /// Returns a sub-slice over `range`, without doing bounds checking.
+    ///
+    /// For a safe alternative see [`slice`].
+    ///
+    /// # Safety
+    /// Calling this method with a range where `range.start > range.end` or
+    /// `range.end > self.len()` is undefined behavior even if the resulting slice is
+    /// not used. These conditions are checked with debug assertions.
+    ///
+    /// [`slice`]: Self::slice
+    #[inline]
+    pub unsafe fn get_unchecked_range(&self, range: Range<usize>) -> ParallelSlice<'_, Param> {
+        debug_assert!(range.start <= range.end && range.end <= self.len);
+        let ptr = Param::ptr_at(self.storage, range.start);
+        ParallelSlice::from_raw_parts(Param::as_storage(ptr), range.end - range.start)
+    }
+
     /// Gets the individual slices for every sub-slice.
     #[inline]
     pub fn as_slices(&self) -> Param::Slices<'_> {
         unsafe { Param::as_slices(Param::as_ptr(self.storage), self.len) }
     }
 
+    /// Gets the individual slices for every sub-slice as fixed-size arrays.
+    ///
+    /// Returns `None` if the length of the slice is not exactly `N`.
+    #[inline]
+    pub fn as_arrays<const N: usize>(&self) -> Option<Param::Arrays<'_, N>> {
+        Param::as_arrays(self.as_slices())
+    }
+
+    /// Gets the slice of the `I`-th field of every element.
+    #[inline]
+    pub fn column<const I: usize>(&self) -> &[<Param as ParallelColumn<I>>::Column]
+    where
+        Param: ParallelColumn<I>,
+    {
+        unsafe {
+            let ptr = Param::column_ptr(Param::as_ptr(self.storage));
+            core::slice::from_raw_parts(ptr, self.len)
+        }
+    }
+
+    /// Transmutes the `I`-th column of the slice to a slice of another type, ensuring
+    /// alignment of the types is maintained.
+    ///
+    /// This wraps [`slice::align_to`] on the slice returned by [`column`]. It splits the
+    /// column into three distinct slices: prefix, correctly aligned middle slice of the
+    /// new type, and the suffix slice.
+    ///
+    /// # Safety
+    /// This method is essentially a [`transmute`] with respect to the elements in the
+    /// returned middle slice, so all of the usual caveats pertaining to [`transmute`]
+    /// also apply here. `U` must be valid for any bit pattern of the column's contents.
+    ///
+    /// [`slice::align_to`]: https://doc.rust-lang.org/std/primitive.slice.html#method.align_to
+    /// [`column`]: Self::column
+    /// [`transmute`]: core::mem::transmute
+    #[inline]
+    pub unsafe fn field_align_to<const I: usize, U>(
+        &self,
+    ) -> (
+        &[<Param as ParallelColumn<I>>::Column],
+        &[U],
+        &[<Param as ParallelColumn<I>>::Column],
+    )
+    where
+        Param: ParallelColumn<I>,
+    {
+        self.column::<I>().align_to::<U>()
+    }
+
+    /// Sums the `I`-th field of every element.
+    #[inline]
+    pub fn sum_field<const I: usize>(&self) -> <Param as ParallelColumn<I>>::Column
+    where
+        Param: ParallelColumn<I>,
+        <Param as ParallelColumn<I>>::Column: for<'b> Sum<&'b <Param as ParallelColumn<I>>::Column>,
+    {
+        self.column::<I>().iter().sum()
+    }
+
+    /// Returns the index of the first element whose `I`-th field is equal to `needle`,
+    /// or [`None`] if there is no such element.
+    ///
+    /// Only the `I`-th column is scanned, which makes this considerably faster than
+    /// searching row-wise with [`Iterator::position`] when the row has several fields.
+    ///
+    /// [`None`]: Option::None
+    #[inline]
+    pub fn field_position_of<const I: usize>(
+        &self,
+        needle: <Param as ParallelColumn<I>>::Column,
+    ) -> Option<usize>
+    where
+        Param: ParallelColumn<I>,
+        <Param as ParallelColumn<I>>::Column: PartialEq,
+    {
+        self.column::<I>().iter().position(|value| *value == needle)
+    }
+
+    /// Folds every `I`-th field of every element into an accumulator by applying an operation,
+    /// returning the final result.
+    #[inline]
+    pub fn fold_field<const I: usize, B, F>(&self, init: B, f: F) -> B
+    where
+        Param: ParallelColumn<I>,
+        F: FnMut(B, &<Param as ParallelColumn<I>>::Column) -> B,
+    {
+        self.column::<I>().iter().fold(init, f)
+    }
+
     /// Returns an iterator over the [`ParallelSlice`].
     pub fn iter(&self) -> Iter<'a, Param> {
         Iter {
@@ -133,6 +308,105 @@ impl<'a, Param: ParallelParam> ParallelSlice<'a, Param> {
         }
     }
 
+    /// Returns an iterator over the [`ParallelSlice`] that also yields the index of each
+    /// element, starting at `offset` instead of 0.
+    ///
+    /// This is useful for recovering the indices of the original vector when
+    /// iterating over a sub-slice that starts at `offset`.
+    pub fn indexed_iter(&self, offset: usize) -> impl Iterator<Item = (usize, Param::Ref<'_>)> {
+        let iter: Iter<'_, Param> = Iter {
+            ptr: Param::as_ptr(self.storage),
+            remaining: self.len,
+            _marker: PhantomData,
+        };
+        iter.enumerate().map(move |(idx, row)| (offset + idx, row))
+    }
+
+    /// Returns an iterator over `N` elements of the slice at a time, starting at the
+    /// beginning of the slice.
+    ///
+    /// The chunks are arrays of references to consecutive elements, and do not
+    /// overlap. If `N` does not divide the length of the slice, then the last up to
+    /// `N-1` elements will be omitted.
+    ///
+    /// # Panics
+    /// This function will panic if `N` is 0.
+    pub fn array_chunks<const N: usize>(&self) -> impl Iterator<Item = [Param::Ref<'_>; N]> {
+        assert!(N != 0, "chunk size must be non-zero");
+        let storage = self.storage;
+        (0..self.len / N).map(move |chunk| {
+            // SAFE: Every chunk lies entirely within the slice.
+            core::array::from_fn(|idx| unsafe {
+                Param::as_ref(Param::ptr_at(storage, chunk * N + idx))
+            })
+        })
+    }
+
+    /// Searches for an element from the back of the slice, returning its index
+    /// from the start of the slice, or [`None`] if no element matches.
+    ///
+    /// [`None`]: Option::None
+    pub fn rposition_by<F>(&self, f: F) -> Option<usize>
+    where
+        F: FnMut(Param::Ref<'_>) -> bool,
+    {
+        self.iter().rposition(f)
+    }
+
+    /// Binary searches this slice with a comparator function.
+    ///
+    /// The comparator function should return an order code that indicates whether
+    /// its argument is `Less`, `Equal` or `Greater` the desired target. If the slice
+    /// is not sorted or if the comparator function does not implement an order
+    /// consistent with the sort order of the underlying slice, the returned result
+    /// is unspecified and meaningless.
+    ///
+    /// If the value is found then [`Result::Ok`] is returned, containing the index
+    /// of the matching element. If there are multiple matches, then any one of the
+    /// matches could be returned. If the value is not found then [`Result::Err`] is
+    /// returned, containing the index where a matching element could be inserted
+    /// while maintaining sorted order.
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(Param::Ref<'_>) -> Ordering,
+    {
+        unsafe {
+            binary_search_ptr::<Param, _>(self.storage, self.len, |ptr| f(Param::as_ref(ptr)))
+        }
+    }
+
+    /// Binary searches this slice with a key extraction function.
+    ///
+    /// Assumes that the slice is sorted by the key. See [`binary_search_by`] for
+    /// details on the return value.
+    ///
+    /// [`binary_search_by`]: Self::binary_search_by
+    pub fn binary_search_by_key<K, F>(&self, key: &K, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(Param::Ref<'_>) -> K,
+        K: Ord,
+    {
+        self.binary_search_by(|row| f(row).cmp(key))
+    }
+
+    /// Binary searches this slice for a given element.
+    ///
+    /// Assumes that the slice is sorted. See [`binary_search_by`] for details on the
+    /// return value.
+    ///
+    /// [`binary_search_by`]: Self::binary_search_by
+    pub fn binary_search<'x>(&self, x: &'x Param) -> Result<usize, usize>
+    where
+        Param::Ref<'x>: Ord,
+    {
+        let target = Param::as_refs(x);
+        unsafe {
+            binary_search_ptr::<Param, _>(self.storage, self.len, |ptr| {
+                Param::as_ref::<'x>(ptr).cmp(&target)
+            })
+        }
+    }
+
     /// Returns an iterator over the [`ParallelSlice`].
     pub fn iters(&self) -> Param::Iters<'_> {
         unsafe {
@@ -141,13 +415,80 @@ impl<'a, Param: ParallelParam> ParallelSlice<'a, Param> {
             Param::iters(slices)
         }
     }
+
+    /// Returns an iterator over the slice producing non-overlapping runs
+    /// of elements using the predicate to separate them.
+    ///
+    /// The predicate is called for every pair of consecutive elements,
+    /// meaning that it is called on `slice[0]` and `slice[1]`,
+    /// followed by `slice[1]` and `slice[2]`, and so on.
+    pub fn chunks_by<F>(&self, pred: F) -> ChunkBy<'_, Param, F>
+    where
+        F: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> bool,
+    {
+        ChunkBy {
+            storage: self.storage,
+            len: self.len,
+            predicate: pred,
+            _marker: PhantomData,
+        }
+    }
 }
 
-impl<'s, 'r, Param> Hash for ParallelSlice<'s, Param>
+/// Binary searches `len` elements starting at `storage`.
+///
+/// # Safety
+/// `storage` must be valid for `len` initialized elements.
+unsafe fn binary_search_ptr<Param, F>(
+    storage: Param::Storage,
+    len: usize,
+    mut f: F,
+) -> Result<usize, usize>
+where
+    Param: ParallelParam,
+    F: FnMut(Param::Ptr) -> Ordering,
+{
+    let base = Param::as_ptr(storage);
+    let mut left = 0;
+    let mut right = len;
+    while left < right {
+        let mid = left + (right - left) / 2;
+        match f(Param::add(base, mid)) {
+            Ordering::Less => left = mid + 1,
+            Ordering::Greater => right = mid,
+            Ordering::Equal => return Ok(mid),
+        }
+    }
+    Err(left)
+}
+
+impl<'a, Param: ParallelParam + Clone> ParallelSlice<'a, Param> {
+    /// Returns an iterator that yields clones of each row in the slice.
+    pub fn cloned(&self) -> impl Iterator<Item = Param> + '_ {
+        let base = Param::as_ptr(self.storage);
+        (0..self.len).map(move |idx| unsafe {
+            // SAFE: The index is always in bounds. The read value is never dropped,
+            // so ownership stays with the slice.
+            let value = ManuallyDrop::new(Param::read(Param::add(base, idx)));
+            (*value).clone()
+        })
+    }
+}
+
+impl<'a, Param: ParallelParam + Copy> ParallelSlice<'a, Param> {
+    /// Returns an iterator that yields copies of each row in the slice by value.
+    pub fn copied(&self) -> impl Iterator<Item = Param> + '_ {
+        let base = Param::as_ptr(self.storage);
+        // SAFE: The index is always in bounds, and `Param: Copy` means reading the
+        // value out does not leave the slice in an invalid state.
+        (0..self.len).map(move |idx| unsafe { Param::read(Param::add(base, idx)) })
+    }
+}
+
+impl<'s, Param> Hash for ParallelSlice<'s, Param>
 where
     Param: ParallelParam + 's,
     Param::Ref<'s>: Hash,
-    'r: 's,
 {
     fn hash<H>(&self, hasher: &mut H)
     where
@@ -160,12 +501,27 @@ where
     }
 }
 
+// SAFE: ParallelSlice has the same semantics as &[T].
+unsafe impl<'a, Param: ParallelParam + Sync> Send for ParallelSlice<'a, Param> {}
+unsafe impl<'a, Param: ParallelParam + Sync> Sync for ParallelSlice<'a, Param> {}
+
 /// A mutable dynamically-sized view into a contiguous heterogeneous sequence.
 /// Contiguous here means that elements are laid out so that every element is
 /// the same distance from its neighbors.
 ///
 /// Unlike a struct of slices, this type only stores one length instead
 /// of duplicating the values across multiple slice fields.
+///
+/// Like `&mut [T]`, a [`ParallelSliceMut`] can change the values it points to, but
+/// not its length. Operations that change the length, such as [`push`], [`insert`],
+/// [`remove`] and [`reserve`], are only available on [`ParallelVec`], even though
+/// [`ParallelVec`] dereferences to [`ParallelSliceMut`].
+///
+/// [`ParallelVec`]: crate::ParallelVec
+/// [`push`]: crate::ParallelVec::push
+/// [`insert`]: crate::ParallelVec::insert
+/// [`remove`]: crate::ParallelVec::remove
+/// [`reserve`]: crate::ParallelVec::reserve
 #[repr(C)]
 pub struct ParallelSliceMut<'a, Param: ParallelParam> {
     // Do not reorder these fields. These must be in the same order as
@@ -243,6 +599,42 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         index.get_mut(self)
     }
 
+    /// Returns mutable references to the elements at each of `indices`, or
+    /// [`None`] if any of them are out of bounds or appear more than once.
+    ///
+    /// The returned references mutably borrow the slice for as long as they are alive.
+    ///
+    /// [`None`]: Option::None
+    #[inline]
+    pub fn get_many_mut<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Option<[Param::RefMut<'_>; N]> {
+        if !are_disjoint_in_bounds(&indices, self.len) {
+            return None;
+        }
+
+        // SAFE: All indices are in bounds and refer to different elements.
+        unsafe { Some(indices.map(|idx| Param::as_mut(Param::ptr_at(self.storage, idx)))) }
+    }
+
+    /// Returns mutable references to the two elements at `indices`, or [`None`]
+    /// if either is out of bounds or both indices are the same.
+    ///
+    /// This is [`get_many_mut`] for a pair of indices, returning a tuple instead
+    /// of an array.
+    ///
+    /// [`None`]: Option::None
+    /// [`get_many_mut`]: Self::get_many_mut
+    #[inline]
+    pub fn get_pair_mut(
+        &mut self,
+        indices: [usize; 2],
+    ) -> Option<(Param::RefMut<'_>, Param::RefMut<'_>)> {
+        let [a, b] = self.get_many_mut(indices)?;
+        Some((a, b))
+    }
+
     /// Returns the first element of the slice, or `None` if it is empty.
     #[inline(always)]
     pub fn first(&self) -> Option<Param::Ref<'_>> {
@@ -275,6 +667,144 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         }
     }
 
+    /// Returns the first and all the rest of the elements of the slice, or `None` if it is empty.
+    pub fn split_first(&self) -> Option<(Param::Ref<'_>, ParallelSlice<'_, Param>)> {
+        if self.len == 0 {
+            return None;
+        }
+        unsafe {
+            let first = Param::as_ref(Param::as_ptr(self.storage));
+            let rest = Param::as_storage(Param::ptr_at(self.storage, 1));
+            Some((first, ParallelSlice::from_raw_parts(rest, self.len - 1)))
+        }
+    }
+
+    /// Returns the last and all the rest of the elements of the slice, or `None` if it is empty.
+    pub fn split_last(&self) -> Option<(Param::Ref<'_>, ParallelSlice<'_, Param>)> {
+        if self.len == 0 {
+            return None;
+        }
+        unsafe {
+            let last = Param::as_ref(Param::ptr_at(self.storage, self.len - 1));
+            Some((
+                last,
+                ParallelSlice::from_raw_parts(self.storage, self.len - 1),
+            ))
+        }
+    }
+
+    /// Returns the first and all the rest of the elements of the slice, or `None` if it is empty.
+    pub fn split_first_mut(&mut self) -> Option<(Param::RefMut<'_>, ParallelSliceMut<'_, Param>)> {
+        if self.len == 0 {
+            return None;
+        }
+        // SAFE: The first element and the rest of the slice do not overlap.
+        unsafe {
+            let first = Param::as_mut(Param::as_ptr(self.storage));
+            let rest = Param::as_storage(Param::ptr_at(self.storage, 1));
+            Some((first, ParallelSliceMut::from_raw_parts(rest, self.len - 1)))
+        }
+    }
+
+    /// Returns the last and all the rest of the elements of the slice, or `None` if it is empty.
+    pub fn split_last_mut(&mut self) -> Option<(Param::RefMut<'_>, ParallelSliceMut<'_, Param>)> {
+        if self.len == 0 {
+            return None;
+        }
+        // SAFE: The last element and the rest of the slice do not overlap.
+        unsafe {
+            let last = Param::as_mut(Param::ptr_at(self.storage, self.len - 1));
+            Some((
+                last,
+                ParallelSliceMut::from_raw_parts(self.storage, self.len - 1),
+            ))
+        }
+    }
+
+    /// Divides the slice into two at an index.
+    ///
+    /// The first will contain all indices from `[0, mid)` (excluding the index `mid`
+    /// itself) and the second will contain all indices from `[mid, len)` (excluding
+    /// the index `len` itself).
+    ///
+    /// # Panics
+    /// This function will panic if `mid > len`.
+    pub fn split_at(&self, mid: usize) -> (ParallelSlice<'_, Param>, ParallelSlice<'_, Param>) {
+        assert_in_bounds_inclusive(mid, self.len);
+        unsafe {
+            let rest = Param::as_storage(Param::ptr_at(self.storage, mid));
+            (
+                ParallelSlice::from_raw_parts(self.storage, mid),
+                ParallelSlice::from_raw_parts(rest, self.len - mid),
+            )
+        }
+    }
+
+    /// Divides one mutable slice into two at an index.
+    ///
+    /// The first will contain all indices from `[0, mid)` (excluding the index `mid`
+    /// itself) and the second will contain all indices from `[mid, len)` (excluding
+    /// the index `len` itself).
+    ///
+    /// # Panics
+    /// This function will panic if `mid > len`.
+    pub fn split_at_mut(
+        &mut self,
+        mid: usize,
+    ) -> (ParallelSliceMut<'_, Param>, ParallelSliceMut<'_, Param>) {
+        assert_in_bounds_inclusive(mid, self.len);
+        // SAFE: The two halves cover disjoint ranges of every field.
+        unsafe {
+            let rest = Param::as_storage(Param::ptr_at(self.storage, mid));
+            (
+                ParallelSliceMut::from_raw_parts(self.storage, mid),
+                ParallelSliceMut::from_raw_parts(rest, self.len - mid),
+            )
+        }
+    }
+
+    /// Gets an immutable view of the elements in `range`.
+    ///
+    /// This is the equivalent of `&slice[range]` for `[T]`. [`Index`] cannot be
+    /// implemented to return a [`ParallelSlice`], as [`Index::index`] must return a
+    /// reference to a value stored within `self`, and a [`ParallelSlice`] is a view
+    /// constructed on demand.
+    ///
+    /// # Panics
+    /// This function will panic if the start of the range is greater than its end,
+    /// or if the end of the range is greater than `self.len`.
+    ///
+    /// [`Index`]: core::ops::Index
+    /// [`Index::index`]: core::ops::Index::index
+    #[inline]
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> ParallelSlice<'_, Param> {
+        let range = to_range(range, self.len);
+        unsafe {
+            let ptr = Param::ptr_at(self.storage, range.start);
+            ParallelSlice::from_raw_parts(Param::as_storage(ptr), range.len())
+        }
+    }
+
+    /// Gets a mutable view of the elements in `range`.
+    ///
+    /// This is the equivalent of `&mut slice[range]` for `[T]`. See [`slice`] for why
+    /// [`IndexMut`] is not implemented.
+    ///
+    /// # Panics
+    /// This function will panic if the start of the range is greater than its end,
+    /// or if the end of the range is greater than `self.len`.
+    ///
+    /// [`slice`]: Self::slice
+    /// [`IndexMut`]: core::ops::IndexMut
+    #[inline]
+    pub fn slice_mut<R: RangeBounds<usize>>(&mut self, range: R) -> ParallelSliceMut<'_, Param> {
+        let range = to_range(range, self.len);
+        unsafe {
+            let ptr = Param::ptr_at(self.storage, range.start);
+            ParallelSliceMut::from_raw_parts(Param::as_storage(ptr), range.len())
+        }
+    }
+
     /// Gets a immutable reference to the elements at `index`.
     ///
     /// # Panics
@@ -299,6 +829,70 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         index.index_mut(self)
     }
 
+    /// Gets mutable references to the elements at each of `indices`.
+    ///
+    /// The returned references mutably borrow the slice for as long as they are alive.
+    ///
+    /// # Panics
+    /// This function will panic if any of `indices` is out of bounds or appears
+    /// more than once.
+    #[inline]
+    pub fn index_many_mut<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> [Param::RefMut<'_>; N] {
+        for (i, idx) in indices.iter().enumerate() {
+            assert_in_bounds(*idx, self.len);
+            assert!(
+                !indices[..i].contains(idx),
+                "Attempted to mutably borrow the same index twice: {}",
+                idx
+            );
+        }
+        // SAFE: All indices are in bounds and refer to different elements.
+        unsafe { indices.map(|idx| Param::as_mut(Param::ptr_at(self.storage, idx))) }
+    }
+
+    /// Gets mutable references to the two elements at `indices`.
+    ///
+    /// This is [`index_many_mut`] for a pair of indices, returning a tuple instead
+    /// of an array.
+    ///
+    /// # Panics
+    /// This function will panic if either index is out of bounds or both indices
+    /// are the same.
+    ///
+    /// [`index_many_mut`]: Self::index_many_mut
+    #[inline]
+    pub fn index_pair_mut(
+        &mut self,
+        indices: [usize; 2],
+    ) -> (Param::RefMut<'_>, Param::RefMut<'_>) {
+        let [a, b] = self.index_many_mut(indices);
+        (a, b)
+    }
+
+    /// Gets a mutable view of the elements in `from..`, without changing the length
+    /// of `self`.
+    ///
+    /// This is similar to `self.index_mut(from..)`, but `from == self.len` yields an
+    /// empty view, and the returned view borrows `self` mutably, so `self` cannot be
+    /// used, or resized if it is a [`ParallelVec`], until the view is dropped. To move
+    /// the tail into a separate allocation, build a new [`ParallelVec`] instead.
+    ///
+    /// # Panics
+    /// This function will panic if `from > self.len`.
+    ///
+    /// [`ParallelVec`]: crate::ParallelVec
+    #[inline]
+    pub fn tail_mut(&mut self, from: usize) -> ParallelSliceMut<'_, Param> {
+        assert_in_bounds_inclusive(from, self.len);
+        unsafe {
+            let ptr = Param::ptr_at(self.storage, from);
+            ParallelSliceMut::from_raw_parts(Param::as_storage(ptr), self.len - from)
+        }
+    }
+
     /// Sets a value at an valid index in the slice.
     ///
     /// # Panics
@@ -311,6 +905,45 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         }
     }
 
+    /// Applies a batch of `(index, value)` updates to the slice, dropping the
+    /// previous value at each index and replacing it with the new one.
+    ///
+    /// Updates are applied in order, so later updates to the same index
+    /// overwrite earlier ones.
+    ///
+    /// # Panics
+    /// This function will panic if any index is out of bounds. Updates preceding
+    /// the out of bounds index will have already been applied.
+    pub fn apply_updates<I>(&mut self, updates: I)
+    where
+        I: IntoIterator<Item = (usize, Param)>,
+    {
+        for (index, value) in updates {
+            self.set(index, value);
+        }
+    }
+
+    /// Calls `f` on every ordered pair of distinct elements, passing a mutable
+    /// reference to the first and an immutable reference to the second.
+    ///
+    /// Pairs are visited in row-major order: `(0, 1)`, `(0, 2)`, ..., `(1, 0)`,
+    /// `(1, 2)`, and so on. An element is never paired with itself.
+    pub fn for_each_pair_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Param::RefMut<'_>, Param::Ref<'_>),
+    {
+        for i in 0..self.len {
+            for j in (0..self.len).filter(|j| *j != i) {
+                // SAFE: Both indices are in bounds, and refer to different elements.
+                unsafe {
+                    let a = Param::as_mut(Param::ptr_at(self.storage, i));
+                    let b = Param::as_ref(Param::ptr_at(self.storage, j));
+                    f(a, b);
+                }
+            }
+        }
+    }
+
     /// Sets a value at an valid index in the slice without
     /// checking bounds.
     ///
@@ -349,6 +982,43 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         Param::as_mut(Param::ptr_at(self.storage, index))
     }
 
+    /// Returns a sub-slice over `range`, without doing bounds checking.
+    ///
+    /// For a safe alternative see [`slice`].
+    ///
+    /// # Safety
+    /// Calling this method with a range where `range.start > range.end` or
+    /// `range.end > self.len()` is undefined behavior even if the resulting slice is
+    /// not used. These conditions are checked with debug assertions.
+    ///
+    /// [`slice`]: Self::slice
+    #[inline]
+    pub unsafe fn get_unchecked_range(&self, range: Range<usize>) -> ParallelSlice<'_, Param> {
+        debug_assert!(range.start <= range.end && range.end <= self.len);
+        let ptr = Param::ptr_at(self.storage, range.start);
+        ParallelSlice::from_raw_parts(Param::as_storage(ptr), range.end - range.start)
+    }
+
+    /// Returns a mutable sub-slice over `range`, without doing bounds checking.
+    ///
+    /// For a safe alternative see [`slice_mut`].
+    ///
+    /// # Safety
+    /// Calling this method with a range where `range.start > range.end` or
+    /// `range.end > self.len()` is undefined behavior even if the resulting slice is
+    /// not used. These conditions are checked with debug assertions.
+    ///
+    /// [`slice_mut`]: Self::slice_mut
+    #[inline]
+    pub unsafe fn get_unchecked_range_mut(
+        &mut self,
+        range: Range<usize>,
+    ) -> ParallelSliceMut<'_, Param> {
+        debug_assert!(range.start <= range.end && range.end <= self.len);
+        let ptr = Param::ptr_at(self.storage, range.start);
+        ParallelSliceMut::from_raw_parts(Param::as_storage(ptr), range.end - range.start)
+    }
+
     /// Sorts the slice with a comparator function.
     ///
     /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate sorting
@@ -381,10 +1051,14 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
     /// This defers to the `core` implemenation of [`slice::sort_by_key`], so any properties it
     /// has will also hold for this function.
     ///
+    /// The key may not borrow from the element it was extracted from. To sort by
+    /// borrowed data, use [`sort_by_key_ref`] instead.
+    ///
     /// [`slice::sort_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_by_key
+    /// [`sort_by_key_ref`]: Self::sort_by_key_ref
     pub fn sort_by_key<K, F>(&mut self, f: F)
     where
-        F: Fn(Param::Ref<'a>) -> K,
+        F: Fn(Param::Ref<'_>) -> K,
         K: Ord,
     {
         let base = Param::as_ptr(self.storage);
@@ -393,6 +1067,34 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         });
     }
 
+    /// Sorts the slice with a key extraction function, where the key is borrowed
+    /// from the element it was extracted from.
+    ///
+    /// This function will allocate `sizeof(usize) * self.len` bytes as an intermediate sorting
+    /// buffer.
+    ///
+    /// The key function is passed a reference to the element's references, so that
+    /// the returned key can borrow from the element for the duration of a single
+    /// comparison. Like [`sort_by_key`], this sort is stable.
+    ///
+    /// [`sort_by_key`]: Self::sort_by_key
+    pub fn sort_by_key_ref<K, F>(&mut self, f: F)
+    where
+        F: for<'b> Fn(&'b Param::Ref<'b>) -> &'b K,
+        K: Ord + ?Sized,
+    {
+        let base = Param::as_ptr(self.storage);
+        self.sort_via(|indices| {
+            indices.sort_by(|a, b| unsafe {
+                // The references have no drop glue, but the compiler can't prove that for
+                // an associated type, so they're wrapped to let the keys borrow from them.
+                let a = ManuallyDrop::new(Param::as_ref(Param::add(base, *a)));
+                let b = ManuallyDrop::new(Param::as_ref(Param::add(base, *b)));
+                f(&a).cmp(f(&b))
+            });
+        });
+    }
+
     /// Sorts the slice with a comparator function, but might not preserve the order of equal
     /// elements.
     ///
@@ -427,10 +1129,14 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
     /// This defers to the `core` implemenation of [`slice::sort_unstable_by_key`], so any properties
     /// it has will also hold this function.
     ///
+    /// The key may not borrow from the element it was extracted from. To compare
+    /// elements by borrowed data, use [`sort_unstable_by`] instead.
+    ///
     /// [`slice::sort_unstable_by_key`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_unstable_by_key
+    /// [`sort_unstable_by`]: Self::sort_unstable_by
     pub fn sort_unstable_by_key<K, F>(&mut self, f: F)
     where
-        F: Fn(Param::Ref<'a>) -> K,
+        F: Fn(Param::Ref<'_>) -> K,
         K: Ord,
     {
         let base = Param::as_ptr(self.storage);
@@ -439,8 +1145,40 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         });
     }
 
+    /// Sorts the slice with a key extraction function, caching the keys.
+    ///
+    /// The key function is called exactly once per element, which makes this
+    /// faster than [`sort_by_key`] for expensive key functions.
+    ///
+    /// This function will allocate `(sizeof(K) + sizeof(usize) * 2) * self.len` bytes as
+    /// intermediate sorting buffers.
+    ///
+    /// This mirrors the `core` implemenation of [`slice::sort_by_cached_key`], so any properties
+    /// it has will also hold for this function.
+    ///
+    /// [`sort_by_key`]: Self::sort_by_key
+    /// [`slice::sort_by_cached_key`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_by_cached_key
+    pub fn sort_by_cached_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(Param::Ref<'_>) -> K,
+        K: Ord,
+    {
+        let base = Param::as_ptr(self.storage);
+        self.sort_via(|indices| {
+            let mut keys: Vec<(K, usize)> = indices
+                .iter()
+                .map(|idx| unsafe { (f(Param::as_ref(Param::add(base, *idx))), *idx) })
+                .collect();
+            // The index is part of the key, so an unstable sort is stable here.
+            keys.sort_unstable();
+            for (dst, (_, idx)) in indices.iter_mut().zip(keys) {
+                *dst = idx;
+            }
+        });
+    }
+
     #[inline(always)]
-    fn sort_via<F>(&mut self, f: F)
+    pub(crate) fn sort_via<F>(&mut self, f: F)
     where
         F: FnOnce(&mut Vec<usize>),
     {
@@ -451,16 +1189,22 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         let mut indices: Vec<usize> = (0..self.len).collect();
         f(&mut indices);
 
+        // Apply the permutation one cycle at a time. `indices[dst]` is the index
+        // of the element that should end up at `dst`. Every visited index is
+        // marked as settled by pointing it at itself.
         // SAFE: All of the indices used here are valid.
         unsafe {
-            for src in 0..self.len {
-                let dst = *indices.get_unchecked(src);
-                if src == dst {
-                    continue;
+            for start in 0..self.len {
+                let mut dst = start;
+                loop {
+                    let src = *indices.get_unchecked(dst);
+                    *indices.get_unchecked_mut(dst) = dst;
+                    if src == start {
+                        break;
+                    }
+                    self.swap_unchecked(dst, src);
+                    dst = src;
                 }
-                self.swap_unchecked(src, dst);
-                // TODO: Use swap_unchecked here when stablized.
-                indices.swap(src, dst);
             }
         }
     }
@@ -483,14 +1227,118 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         unsafe { Param::as_slices(Param::as_ptr(self.storage), self.len) }
     }
 
+    /// Gets the individual slices for every sub-slice as fixed-size arrays.
+    ///
+    /// Returns `None` if the length of the slice is not exactly `N`.
+    #[inline]
+    pub fn as_arrays<const N: usize>(&self) -> Option<Param::Arrays<'_, N>> {
+        Param::as_arrays(self.as_slices())
+    }
+
+    /// Gets the slice of the `I`-th field of every element.
+    #[inline]
+    pub fn column<const I: usize>(&self) -> &[<Param as ParallelColumn<I>>::Column]
+    where
+        Param: ParallelColumn<I>,
+    {
+        unsafe {
+            let ptr = Param::column_ptr(Param::as_ptr(self.storage));
+            core::slice::from_raw_parts(ptr, self.len)
+        }
+    }
+
+    /// Transmutes the `I`-th column of the slice to a slice of another type, ensuring
+    /// alignment of the types is maintained.
+    ///
+    /// This wraps [`slice::align_to`] on the slice returned by [`column`]. It splits the
+    /// column into three distinct slices: prefix, correctly aligned middle slice of the
+    /// new type, and the suffix slice.
+    ///
+    /// # Safety
+    /// This method is essentially a [`transmute`] with respect to the elements in the
+    /// returned middle slice, so all of the usual caveats pertaining to [`transmute`]
+    /// also apply here. `U` must be valid for any bit pattern of the column's contents.
+    ///
+    /// [`slice::align_to`]: https://doc.rust-lang.org/std/primitive.slice.html#method.align_to
+    /// [`column`]: Self::column
+    /// [`transmute`]: core::mem::transmute
+    #[inline]
+    pub unsafe fn field_align_to<const I: usize, U>(
+        &self,
+    ) -> (
+        &[<Param as ParallelColumn<I>>::Column],
+        &[U],
+        &[<Param as ParallelColumn<I>>::Column],
+    )
+    where
+        Param: ParallelColumn<I>,
+    {
+        self.column::<I>().align_to::<U>()
+    }
+
+    /// Sums the `I`-th field of every element.
+    #[inline]
+    pub fn sum_field<const I: usize>(&self) -> <Param as ParallelColumn<I>>::Column
+    where
+        Param: ParallelColumn<I>,
+        <Param as ParallelColumn<I>>::Column: for<'b> Sum<&'b <Param as ParallelColumn<I>>::Column>,
+    {
+        self.column::<I>().iter().sum()
+    }
+
+    /// Returns the index of the first element whose `I`-th field is equal to `needle`,
+    /// or [`None`] if there is no such element.
+    ///
+    /// Only the `I`-th column is scanned, which makes this considerably faster than
+    /// searching row-wise with [`Iterator::position`] when the row has several fields.
+    ///
+    /// [`None`]: Option::None
+    #[inline]
+    pub fn field_position_of<const I: usize>(
+        &self,
+        needle: <Param as ParallelColumn<I>>::Column,
+    ) -> Option<usize>
+    where
+        Param: ParallelColumn<I>,
+        <Param as ParallelColumn<I>>::Column: PartialEq,
+    {
+        self.column::<I>().iter().position(|value| *value == needle)
+    }
+
+    /// Folds every `I`-th field of every element into an accumulator by applying an operation,
+    /// returning the final result.
+    #[inline]
+    pub fn fold_field<const I: usize, B, F>(&self, init: B, f: F) -> B
+    where
+        Param: ParallelColumn<I>,
+        F: FnMut(B, &<Param as ParallelColumn<I>>::Column) -> B,
+    {
+        self.column::<I>().iter().fold(init, f)
+    }
+
     /// Gets mutable individual slices for every sub-slice.
     #[inline]
     pub fn as_slices_mut(&mut self) -> Param::SlicesMut<'_> {
         unsafe { Param::as_slices_mut(self.as_mut_ptrs(), self.len) }
     }
 
+    /// Gets the mutable slice of the `I`-th field of every element.
+    #[inline]
+    pub fn column_mut<const I: usize>(&mut self) -> &mut [<Param as ParallelColumn<I>>::Column]
+    where
+        Param: ParallelColumn<I>,
+    {
+        unsafe {
+            let ptr = Param::column_ptr(Param::as_ptr(self.storage));
+            core::slice::from_raw_parts_mut(ptr, self.len)
+        }
+    }
+
     /// Swaps two elements.
     ///
+    /// Every field of the two elements is swapped, so this performs one swap per
+    /// field in `Param`, regardless of the length of the slice.
+    ///
     /// # Arguments
     ///  - `a` - The index of the first element
     ///  - `b` - The index of the second element
@@ -503,6 +1351,17 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         unsafe { self.swap_unchecked(a, b) }
     }
 
+    /// Swaps two rows. This is an alias for [`swap`].
+    ///
+    /// # Panics
+    /// Panics if a or b are out of bounds.
+    ///
+    /// [`swap`]: Self::swap
+    #[inline]
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        self.swap(a, b);
+    }
+
     /// Swaps two elements in the slice, without doing bounds checking.
     ///
     /// For a safe alternative see [`swap`].
@@ -522,6 +1381,26 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         Param::swap(Param::add(base, a), Param::add(base, b));
     }
 
+    /// Swaps multiple pairs of elements in the slice, in order, without doing
+    /// bounds checking.
+    ///
+    /// This is equivalent to calling [`swap_unchecked`] on every pair, but avoids
+    /// repeatedly recomputing the base pointers of the slice.
+    ///
+    /// # Safety
+    /// Calling this method with an out-of-bounds index is undefined behavior.
+    /// The caller has to ensure that both indices of every pair are less than
+    /// `self.len()`.
+    ///
+    /// [`swap_unchecked`]: Self::swap_unchecked
+    #[inline]
+    pub unsafe fn swap_many_unchecked(&mut self, pairs: &[(usize, usize)]) {
+        let base = Param::as_ptr(self.storage);
+        for &(a, b) in pairs {
+            Param::swap(Param::add(base, a), Param::add(base, b));
+        }
+    }
+
     /// Reverses the order of elements in the [`ParallelSliceMut`], in place.
     ///
     /// This is a `O(n)` operation.
@@ -566,6 +1445,51 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         }
     }
 
+    /// Returns an iterator over the [`ParallelSliceMut`] that also yields the index of each
+    /// element, starting at `offset` instead of 0.
+    ///
+    /// This is useful for recovering the indices of the original vector when
+    /// iterating over a sub-slice that starts at `offset`.
+    pub fn indexed_iter(&self, offset: usize) -> impl Iterator<Item = (usize, Param::Ref<'_>)> {
+        let iter: Iter<'_, Param> = Iter {
+            ptr: Param::as_ptr(self.storage),
+            remaining: self.len,
+            _marker: PhantomData,
+        };
+        iter.enumerate().map(move |(idx, row)| (offset + idx, row))
+    }
+
+    /// Returns an iterator over `N` elements of the slice at a time, starting at the
+    /// beginning of the slice.
+    ///
+    /// The chunks are arrays of references to consecutive elements, and do not
+    /// overlap. If `N` does not divide the length of the slice, then the last up to
+    /// `N-1` elements will be omitted.
+    ///
+    /// # Panics
+    /// This function will panic if `N` is 0.
+    pub fn array_chunks<const N: usize>(&self) -> impl Iterator<Item = [Param::Ref<'_>; N]> {
+        assert!(N != 0, "chunk size must be non-zero");
+        let storage = self.storage;
+        (0..self.len / N).map(move |chunk| {
+            // SAFE: Every chunk lies entirely within the slice.
+            core::array::from_fn(|idx| unsafe {
+                Param::as_ref(Param::ptr_at(storage, chunk * N + idx))
+            })
+        })
+    }
+
+    /// Searches for an element from the back of the slice, returning its index
+    /// from the start of the slice, or [`None`] if no element matches.
+    ///
+    /// [`None`]: Option::None
+    pub fn rposition_by<F>(&self, f: F) -> Option<usize>
+    where
+        F: FnMut(Param::Ref<'_>) -> bool,
+    {
+        self.iter().rposition(f)
+    }
+
     /// Returns an iterator that allows modifying each value.
     pub fn iter_mut(&mut self) -> IterMut<'a, Param> {
         IterMut {
@@ -575,6 +1499,99 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
         }
     }
 
+    /// Calls `f` on a mutable reference to every element, in order.
+    ///
+    /// This is equivalent to `self.iter_mut().for_each(f)`, but walks the slice
+    /// with a plain index loop, which can be easier for the compiler to optimize.
+    #[inline]
+    pub fn for_each_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Param::RefMut<'_>),
+    {
+        let base = self.as_mut_ptrs();
+        for idx in 0..self.len {
+            // SAFE: `idx` is in bounds, and each element is only visited once.
+            unsafe { f(Param::as_mut(Param::add(base, idx))) }
+        }
+    }
+
+    /// Returns an iterator that allows modifying every `step`-th value, starting
+    /// with the first.
+    ///
+    /// Unlike `iter_mut().step_by(step)`, this advances the underlying pointers
+    /// directly by `step` elements on every iteration.
+    ///
+    /// # Panics
+    /// This function will panic if `step` is 0.
+    pub fn iter_mut_step_by(&mut self, step: usize) -> StepIterMut<'_, Param> {
+        assert!(step != 0, "step must be non-zero");
+        let remaining = if self.len == 0 {
+            0
+        } else {
+            (self.len - 1) / step + 1
+        };
+        StepIterMut {
+            ptr: self.as_mut_ptrs(),
+            remaining,
+            step,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Binary searches this slice with a comparator function.
+    ///
+    /// The comparator function should return an order code that indicates whether
+    /// its argument is `Less`, `Equal` or `Greater` the desired target. If the slice
+    /// is not sorted or if the comparator function does not implement an order
+    /// consistent with the sort order of the underlying slice, the returned result
+    /// is unspecified and meaningless.
+    ///
+    /// If the value is found then [`Result::Ok`] is returned, containing the index
+    /// of the matching element. If there are multiple matches, then any one of the
+    /// matches could be returned. If the value is not found then [`Result::Err`] is
+    /// returned, containing the index where a matching element could be inserted
+    /// while maintaining sorted order.
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(Param::Ref<'_>) -> Ordering,
+    {
+        unsafe {
+            binary_search_ptr::<Param, _>(self.storage, self.len, |ptr| f(Param::as_ref(ptr)))
+        }
+    }
+
+    /// Binary searches this slice with a key extraction function.
+    ///
+    /// Assumes that the slice is sorted by the key. See [`binary_search_by`] for
+    /// details on the return value.
+    ///
+    /// [`binary_search_by`]: Self::binary_search_by
+    pub fn binary_search_by_key<K, F>(&self, key: &K, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(Param::Ref<'_>) -> K,
+        K: Ord,
+    {
+        self.binary_search_by(|row| f(row).cmp(key))
+    }
+
+    /// Binary searches this slice for a given element.
+    ///
+    /// Assumes that the slice is sorted. See [`binary_search_by`] for details on the
+    /// return value.
+    ///
+    /// [`binary_search_by`]: Self::binary_search_by
+    pub fn binary_search<'x>(&self, x: &'x Param) -> Result<usize, usize>
+    where
+        Param::Ref<'x>: Ord,
+    {
+        let target = Param::as_refs(x);
+        unsafe {
+            binary_search_ptr::<Param, _>(self.storage, self.len, |ptr| {
+                Param::as_ref::<'x>(ptr).cmp(&target)
+            })
+        }
+    }
+
     /// Returns an iterator over the [`ParallelSliceMut`].
     pub fn iters(&self) -> Param::Iters<'_> {
         unsafe {
@@ -592,14 +1609,90 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
             Param::iters_mut(slices)
         }
     }
+
+    /// Returns an iterator over the slice producing non-overlapping runs
+    /// of elements using the predicate to separate them.
+    ///
+    /// The predicate is called for every pair of consecutive elements,
+    /// meaning that it is called on `slice[0]` and `slice[1]`,
+    /// followed by `slice[1]` and `slice[2]`, and so on.
+    pub fn chunks_by<F>(&self, pred: F) -> ChunkBy<'_, Param, F>
+    where
+        F: FnMut(Param::Ref<'_>, Param::Ref<'_>) -> bool,
+    {
+        ChunkBy {
+            storage: self.storage,
+            len: self.len,
+            predicate: pred,
+            _marker: PhantomData,
+        }
+    }
+}
+
+// SAFE: ParallelSliceMut has the same semantics as &mut [T].
+unsafe impl<'a, Param: ParallelParam + Send> Send for ParallelSliceMut<'a, Param> {}
+unsafe impl<'a, Param: ParallelParam + Sync> Sync for ParallelSliceMut<'a, Param> {}
+
+impl<'a, T1: 'static, T2: 'static> ParallelSliceMut<'a, (T1, T2)> {
+    /// Splits the slice into a mutable slice of the first field and an immutable
+    /// slice of the second field.
+    ///
+    /// This allows mutating one column while reading the other.
+    #[inline]
+    pub fn split2_mut(&mut self) -> (&mut [T1], &[T2]) {
+        let (first, second) = self.as_slices_mut();
+        (first, second)
+    }
+
+    /// Returns an iterator that yields a mutable reference to the first field and an
+    /// immutable reference to the second field of each element.
+    ///
+    /// This allows updating one field based on the other without requiring
+    /// exclusive access to both.
+    #[inline]
+    pub fn iter_mut_read(
+        &mut self,
+    ) -> Zip<core::slice::IterMut<'_, T1>, core::slice::Iter<'_, T2>> {
+        let (first, second) = self.split2_mut();
+        first.iter_mut().zip(second.iter())
+    }
 }
 
 impl<'a, Param: ParallelParam + Clone> ParallelSliceMut<'a, Param> {
+    /// Returns an iterator that yields clones of each row in the slice.
+    pub fn cloned(&self) -> impl Iterator<Item = Param> + '_ {
+        let base = Param::as_ptr(self.storage);
+        (0..self.len).map(move |idx| unsafe {
+            // SAFE: The index is always in bounds. The read value is never dropped,
+            // so ownership stays with the slice.
+            let value = ManuallyDrop::new(Param::read(Param::add(base, idx)));
+            (*value).clone()
+        })
+    }
+
     /// Fills self with elements by cloning value.
     #[inline(always)]
     pub fn fill(&mut self, value: Param) {
         self.fill_with(|| value.clone());
     }
+
+    /// Fills the elements in `range` by cloning `value`. The previous values
+    /// in the range are dropped.
+    ///
+    /// # Panics
+    /// This function will panic if the range is out of bounds.
+    pub fn fill_range<R: RangeBounds<usize>>(&mut self, range: R, value: Param) {
+        let range = to_range(range, self.len);
+        if range.is_empty() {
+            return;
+        }
+        unsafe {
+            for idx in range.start..range.end - 1 {
+                self.set_unchecked(idx, value.clone());
+            }
+            self.set_unchecked(range.end - 1, value);
+        }
+    }
 }
 
 impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
@@ -618,11 +1711,20 @@ impl<'a, Param: ParallelParam> ParallelSliceMut<'a, Param> {
     }
 }
 
-impl<'s, 'r, Param> Hash for ParallelSliceMut<'s, Param>
+impl<'a, Param: ParallelParam + Copy> ParallelSliceMut<'a, Param> {
+    /// Returns an iterator that yields copies of each row in the slice by value.
+    pub fn copied(&self) -> impl Iterator<Item = Param> + '_ {
+        let base = Param::as_ptr(self.storage);
+        // SAFE: The index is always in bounds, and `Param: Copy` means reading the
+        // value out does not leave the slice in an invalid state.
+        (0..self.len).map(move |idx| unsafe { Param::read(Param::add(base, idx)) })
+    }
+}
+
+impl<'s, Param> Hash for ParallelSliceMut<'s, Param>
 where
     Param: ParallelParam + 's,
     Param::Ref<'s>: Hash,
-    'r: 's,
 {
     fn hash<H>(&self, hasher: &mut H)
     where
@@ -695,6 +1797,18 @@ impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>
     }
 }
 
+/// Checks that all of the provided indices are in bounds and distinct from
+/// one another.
+#[inline]
+fn are_disjoint_in_bounds(indices: &[usize], len: usize) -> bool {
+    for (i, idx) in indices.iter().enumerate() {
+        if *idx >= len || indices[..i].contains(idx) {
+            return false;
+        }
+    }
+    true
+}
+
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for Range<usize> {
     type Output = ParallelSlice<'s, Param>;
     fn get(self, slice: &ParallelSlice<'s, Param>) -> Option<Self::Output> {
@@ -896,6 +2010,60 @@ impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>
     }
 }
 
+impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>>
+    for RangeToInclusive<usize>
+{
+    type Output = ParallelSlice<'s, Param>;
+    fn get(self, slice: &ParallelSlice<'s, Param>) -> Option<Self::Output> {
+        let end = self.end.checked_add(1)?;
+        Range { start: 0, end }.get(slice)
+    }
+
+    fn index(self, slice: &ParallelSlice<'s, Param>) -> Self::Output {
+        let end = self
+            .end
+            .checked_add(1)
+            .unwrap_or_else(|| out_of_bounds(self.end, slice.len));
+        Range { start: 0, end }.index(slice)
+    }
+}
+
+impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSliceMut<'s, Param>>
+    for RangeToInclusive<usize>
+{
+    type Output = ParallelSlice<'s, Param>;
+    fn get(self, slice: &ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
+        let end = self.end.checked_add(1)?;
+        Range { start: 0, end }.get(slice)
+    }
+
+    fn index(self, slice: &ParallelSliceMut<'s, Param>) -> Self::Output {
+        let end = self
+            .end
+            .checked_add(1)
+            .unwrap_or_else(|| out_of_bounds(self.end, slice.len));
+        Range { start: 0, end }.index(slice)
+    }
+}
+
+impl<'s, Param: ParallelParam> ParallelSliceIndexMut<ParallelSliceMut<'s, Param>>
+    for RangeToInclusive<usize>
+{
+    type Output = ParallelSliceMut<'s, Param>;
+    fn get_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Option<Self::Output> {
+        let end = self.end.checked_add(1)?;
+        Range { start: 0, end }.get_mut(slice)
+    }
+
+    fn index_mut(self, slice: &mut ParallelSliceMut<'s, Param>) -> Self::Output {
+        let end = self
+            .end
+            .checked_add(1)
+            .unwrap_or_else(|| out_of_bounds(self.end, slice.len));
+        Range { start: 0, end }.index_mut(slice)
+    }
+}
+
 impl<'s, Param: ParallelParam> ParallelSliceIndex<ParallelSlice<'s, Param>> for RangeFrom<usize> {
     type Output = ParallelSlice<'s, Param>;
     fn get(self, slice: &ParallelSlice<'s, Param>) -> Option<Self::Output> {
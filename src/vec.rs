@@ -1,9 +1,19 @@
-use crate::{assert_in_bounds, iter::IntoIter, out_of_bounds, ParallelParam, ParallelSliceMut};
+use crate::{
+    assert_in_bounds,
+    entry::{Entry, OccupiedEntry, VacantEntry},
+    iter::{Drain, IntoIter, Iter, IterMut},
+    out_of_bounds,
+    slice::{ParallelSliceIndex, ParallelSliceIndexMut},
+    to_range, Allocator, Global, ParallelParam, ParallelSlice, ParallelSliceMut, TryReserveError,
+};
 use alloc::vec::Vec;
 use core::{
+    cmp::Ordering,
     fmt::{Debug, Formatter},
     hash::{Hash, Hasher},
-    ops::{Deref, DerefMut},
+    marker::PhantomData,
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut, RangeBounds},
 };
 
 /// A contiguously growable heterogenous array type.
@@ -16,12 +26,23 @@ use core::{
 /// allocation pressure. It also only stores one length and capacity instead
 /// of duplicating the values across multiple `Vec` fields.
 ///
+/// Every field is allocated from the same [`Allocator`], which defaults to
+/// [`Global`]. Vectors using other allocators are created with [`new_in`] or
+/// [`with_capacity_in`]. Conversions that hand the columns over to or take them
+/// from other collections, such as [`into_boxed_slices`] or [`into_iter`], are
+/// only available with the global allocator.
+///
 /// [structures of arrays]: https://en.wikipedia.org/wiki/AoS_and_SoA#Structure_of_arrays
+/// [`new_in`]: Self::new_in
+/// [`with_capacity_in`]: Self::with_capacity_in
+/// [`into_boxed_slices`]: Self::into_boxed_slices
+/// [`into_iter`]: Self::into_iter
 #[repr(C)]
-pub struct ParallelVec<Param: ParallelParam> {
+pub struct ParallelVec<Param: ParallelParam, A: Allocator = Global> {
     pub(crate) len: usize,
     pub(crate) storage: Param::Storage,
     pub(crate) capacity: usize,
+    pub(crate) allocator: A,
 }
 
 impl<Param: ParallelParam> ParallelVec<Param> {
@@ -39,7 +60,58 @@ impl<Param: ParallelParam> ParallelVec<Param> {
     ///
     /// It is important to note that although the returned vector has the capacity specified,
     /// the vector will have a zero length.
+    ///
+    /// If every field of `Param` is zero-sized, no memory is allocated regardless of the
+    /// requested capacity, and the vector can hold up to `capacity` elements (up to
+    /// `usize::MAX`) without ever allocating, similar to `Vec<()>`.
     pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+
+    /// Converts a [`Vec`] of rows, an array of structures, into a [`ParallelVec`].
+    ///
+    /// This allocates exactly `vec.len()` elements up front, then moves every field
+    /// of every row into place in a single pass.
+    pub fn from_aos(vec: Vec<Param>) -> Self {
+        let len = vec.len();
+        let mut parallel_vec = Self::with_capacity(len);
+        unsafe {
+            let base = Param::as_ptr(parallel_vec.storage);
+            for (idx, param) in vec.into_iter().enumerate() {
+                Param::write(Param::add(base, idx), param);
+            }
+        }
+        parallel_vec.len = len;
+        parallel_vec
+    }
+
+    /// Converts the vector into a set of boxed slices, one per field.
+    ///
+    /// This will drop any excess capacity, similar to [`Vec::into_boxed_slice`].
+    pub fn into_boxed_slices(mut self) -> Param::BoxedSlices {
+        self.shrink_to_fit();
+        let slices = unsafe { Param::into_boxed_slices(Param::as_ptr(self.storage), self.len) };
+        // The allocations are now owned by the boxed slices.
+        core::mem::forget(self);
+        slices
+    }
+}
+
+impl<Param: ParallelParam, A: Allocator> ParallelVec<Param, A> {
+    /// Constructs a new, empty [`ParallelVec`] that allocates from `allocator`.
+    ///
+    /// The vector will not allocate until elements are pushed onto it.
+    pub fn new_in(allocator: A) -> Self {
+        Self::with_capacity_in(0, allocator)
+    }
+
+    /// Constructs a new, empty [`ParallelVec`] with the specified capacity, allocated
+    /// from `allocator`.
+    ///
+    /// See [`with_capacity`] for details.
+    ///
+    /// [`with_capacity`]: ParallelVec::with_capacity
+    pub fn with_capacity_in(capacity: usize, allocator: A) -> Self {
         unsafe {
             Self {
                 len: 0,
@@ -47,17 +119,154 @@ impl<Param: ParallelParam> ParallelVec<Param> {
                 storage: if capacity == 0 {
                     Param::dangling()
                 } else {
-                    Param::alloc(capacity)
+                    Param::alloc(capacity, &allocator)
                 },
+                allocator,
             }
         }
     }
 
+    /// Returns a reference to the allocator the vector's columns are allocated from.
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        &self.allocator
+    }
+
     /// Returns the number of elements the vector can hold without reallocating.
+    ///
+    /// This is a row count shared by every field. Zero-sized fields never allocate
+    /// and can hold any number of elements, so when `Param` mixes zero-sized and
+    /// non-zero-sized fields, the capacity is the bound imposed by the non-zero-sized
+    /// fields' allocations.
+    ///
+    /// ```
+    /// use parallel_vec::ParallelVec;
+    ///
+    /// let mut vec: ParallelVec<((), u64)> = ParallelVec::with_capacity(10);
+    /// assert_eq!(vec.capacity(), 10);
+    /// for i in 0..10 {
+    ///     vec.push(((), i));
+    /// }
+    /// assert_eq!(vec.capacity(), 10);
+    /// ```
     pub fn capacity(&self) -> usize {
         self.capacity
     }
 
+    /// Returns the number of fields in each element, or the number of columns
+    /// in the vector.
+    ///
+    /// This is the same as [`ParallelParam::FIELD_COUNT`].
+    ///
+    /// ```
+    /// use parallel_vec::ParallelVec;
+    ///
+    /// assert_eq!(ParallelVec::<(u8, u16, u32)>::field_count(), 3);
+    /// ```
+    #[inline]
+    pub const fn field_count() -> usize {
+        Param::FIELD_COUNT
+    }
+
+    /// Gets the individual slices for every field.
+    ///
+    /// Unlike going through [`Deref`], the returned slices borrow from the vector
+    /// itself, so this only requires a shared reference.
+    #[inline]
+    pub fn as_slices(&self) -> Param::Slices<'_> {
+        unsafe { Param::as_slices(Param::as_ptr(self.storage), self.len) }
+    }
+
+    /// Checks that every field's slice is exactly [`len`] elements long.
+    ///
+    /// This is a self-check for code that manipulates the vector's storage
+    /// directly, and does nothing unless debug assertions are enabled.
+    ///
+    /// # Panics
+    /// With debug assertions enabled, this function will panic if any field's
+    /// slice has a different length from the vector.
+    ///
+    /// [`len`]: ParallelSliceMut::len
+    #[inline]
+    pub fn assert_field_lens(&self) {
+        debug_assert_eq!(
+            Param::get_slices_len(&self.as_slices()),
+            Some(self.len),
+            "field lengths do not match the vector's length"
+        );
+    }
+
+    /// Returns a reference to the element or subslice at `index`, if available,
+    /// or [`None`] if it is out of bounds.
+    ///
+    /// Unlike going through [`Deref`], the result borrows from the vector itself.
+    ///
+    /// [`None`]: Option::None
+    #[inline]
+    pub fn get<'b, I>(&'b self, index: I) -> Option<I::Output>
+    where
+        I: ParallelSliceIndex<ParallelSlice<'b, Param>>,
+    {
+        let slice = unsafe { ParallelSlice::from_raw_parts(self.storage, self.len) };
+        index.get(&slice)
+    }
+
+    /// Returns a mutable reference to the element or subslice at `index`, if
+    /// available, or [`None`] if it is out of bounds.
+    ///
+    /// Unlike going through [`DerefMut`], the result mutably borrows the vector
+    /// for as long as it is alive.
+    ///
+    /// [`None`]: Option::None
+    #[inline]
+    pub fn get_mut<'b, I>(&'b mut self, index: I) -> Option<I::Output>
+    where
+        I: ParallelSliceIndexMut<ParallelSliceMut<'b, Param>>,
+    {
+        let mut slice = unsafe { ParallelSliceMut::from_raw_parts(self.storage, self.len) };
+        index.get_mut(&mut slice)
+    }
+
+    /// Gets a immutable reference to the element or subslice at `index`.
+    ///
+    /// Unlike going through [`Deref`], the result borrows from the vector itself.
+    ///
+    /// # Panics
+    /// This function will panic if `index` is out of bounds.
+    #[inline]
+    pub fn index<'b, I>(&'b self, index: I) -> I::Output
+    where
+        I: ParallelSliceIndex<ParallelSlice<'b, Param>>,
+    {
+        let slice = unsafe { ParallelSlice::from_raw_parts(self.storage, self.len) };
+        index.index(&slice)
+    }
+
+    /// Gets a mutable reference to the element or subslice at `index`.
+    ///
+    /// Unlike going through [`DerefMut`], the result mutably borrows the vector
+    /// for as long as it is alive.
+    ///
+    /// # Panics
+    /// This function will panic if `index` is out of bounds.
+    #[inline]
+    pub fn index_mut<'b, I>(&'b mut self, index: I) -> I::Output
+    where
+        I: ParallelSliceIndexMut<ParallelSliceMut<'b, Param>>,
+    {
+        let mut slice = unsafe { ParallelSliceMut::from_raw_parts(self.storage, self.len) };
+        index.index_mut(&mut slice)
+    }
+
+    /// Returns an iterator that allows modifying each value.
+    ///
+    /// Unlike going through [`Deref`], the returned iterator mutably borrows the
+    /// vector for as long as it is alive.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, Param> {
+        self.into_iter()
+    }
+
     /// Clears the vector, removing all values.
     ///
     /// Note that this method has no effect on the allocated capacity of the vector.
@@ -69,22 +278,59 @@ impl<Param: ParallelParam> ParallelVec<Param> {
     ///
     /// If `len` is greater than the vector’s current length, this has no effect.
     ///
+    /// The removed elements are dropped one field at a time: every removed value of
+    /// the first field is dropped from front to back, then every removed value of the
+    /// second field, and so on. Unlike [`Vec::truncate`], this means that the fields of
+    /// one element are not dropped together.
+    ///
     /// Note that this method has no effect on the allocated capacity of the vector.
     pub fn truncate(&mut self, len: usize) {
+        debug_assert!(self.len <= self.capacity, "length exceeds capacity");
         if self.len <= len {
             return;
         }
+        let end = self.len;
+        // Set len first in case one of the Drop impls panics.
+        self.len = len;
         unsafe {
-            self.drop_range(len, self.len);
-            self.len = len;
+            self.drop_range(len, end);
         }
     }
 
-    pub(crate) unsafe fn drop_range(&mut self, start: usize, end: usize) {
-        let base = Param::as_ptr(self.storage);
-        for idx in start..end {
-            Param::drop(Param::add(base, idx));
+    /// Shortens the vector, keeping the first `len` elements and dropping the rest,
+    /// and returns the number of elements that were dropped.
+    ///
+    /// See [`truncate`] for more details.
+    ///
+    /// [`truncate`]: Self::truncate
+    pub fn truncate_returning(&mut self, len: usize) -> usize {
+        let removed = self.len.saturating_sub(len);
+        self.truncate(len);
+        removed
+    }
+
+    /// Shortens the vector, keeping the first `len` elements and passing each removed
+    /// element by value to `f`, from front to back.
+    ///
+    /// `f` takes ownership of the removed elements, and is responsible for dropping
+    /// them. If `len` is greater than the vector’s current length, this has no effect.
+    ///
+    /// If `f` panics, the elements that were not yet passed to it are dropped.
+    ///
+    /// Note that this method has no effect on the allocated capacity of the vector.
+    pub fn truncate_with<F>(&mut self, len: usize, f: F)
+    where
+        F: FnMut(Param),
+    {
+        if self.len <= len {
+            return;
         }
+        // Draining the tail drops any remaining rows if `f` panics.
+        self.drain(len..).for_each(f);
+    }
+
+    pub(crate) unsafe fn drop_range(&mut self, start: usize, end: usize) {
+        Param::drop_range(Param::ptr_at(self.storage, start), end - start);
     }
 
     /// Shrinks the capacity of the vector with a lower bound.
@@ -94,12 +340,13 @@ impl<Param: ParallelParam> ParallelVec<Param> {
     ///
     /// If the current capacity is less than the lower limit, this is a no-op.
     pub fn shrink_to(&mut self, min_capacity: usize) {
-        if min_capacity > self.capacity {
+        debug_assert!(self.len <= self.capacity, "length exceeds capacity");
+        let capacity = core::cmp::max(self.len, min_capacity);
+        if capacity >= self.capacity {
             return;
         }
-        let capacity = core::cmp::max(self.len, min_capacity);
         unsafe {
-            self.storage = Param::realloc(self.storage, self.capacity, capacity);
+            self.storage = Param::realloc(self.storage, self.capacity, capacity, &self.allocator);
         }
         self.capacity = capacity;
     }
@@ -112,8 +359,56 @@ impl<Param: ParallelParam> ParallelVec<Param> {
         self.shrink_to(self.len);
     }
 
+    /// Removes the specified range from the vector in bulk, returning all removed
+    /// elements as an iterator. If the iterator is dropped before being fully
+    /// consumed, it drops the remaining removed elements.
+    ///
+    /// The returned iterator keeps a mutable borrow on the vector to optimize its
+    /// implementation.
+    ///
+    /// # Panics
+    /// This function will panic if the start of the range is greater than its end,
+    /// or if the end of the range is greater than the length of the vector.
+    ///
+    /// # Leaking
+    /// If the returned iterator goes out of scope without being dropped (due to
+    /// [`core::mem::forget`], for example), the vector may have lost and leaked
+    /// elements arbitrarily, including elements outside the range.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, Param, A> {
+        let range = to_range(range, self.len);
+        let tail_len = self.len - range.end;
+        // Shorten the vector first, so that leaking the iterator only leaks
+        // elements instead of exposing moved-out ones.
+        self.len = range.start;
+        Drain {
+            vec: self,
+            idx: range.start,
+            end: range.end,
+            tail_start: range.end,
+            tail_len,
+        }
+    }
+
     /// Moves all the elements of `other` into `Self`, leaving `other` empty.
-    pub fn append(&mut self, other: &mut ParallelVec<Param>) {
+    ///
+    /// Both vectors must use the same [`Allocator`] type. Only the elements are
+    /// moved: `other` keeps its allocation, which is never freed or reused by
+    /// `self`, so the two vectors may still use different instances of it.
+    ///
+    /// Does nothing if `other` is empty. If `self` is empty, the rows of `other`
+    /// are copied into `self`'s own allocation.
+    pub fn append(&mut self, other: &mut ParallelVec<Param, A>) {
+        if other.len == 0 {
+            return;
+        }
+        // `self` and `other` are distinct mutable borrows, so they must not share
+        // storage, and the copy below never overlaps.
+        debug_assert!(
+            core::mem::size_of::<Param>() == 0
+                || self.capacity == 0
+                || self.storage != other.storage,
+            "appending a vector to itself"
+        );
         self.reserve(other.len);
         unsafe {
             let src = Param::as_ptr(other.storage);
@@ -126,8 +421,21 @@ impl<Param: ParallelParam> ParallelVec<Param> {
         }
     }
 
+    /// Moves all the elements of `other` into `Self`, consuming `other` and
+    /// freeing its allocation.
+    ///
+    /// This is the by-value counterpart to [`append`]. Rows cannot be moved out of a
+    /// [`ParallelSliceMut`], as slices do not own the values they point to; only a
+    /// [`ParallelVec`] can give up ownership of its elements.
+    ///
+    /// [`append`]: Self::append
+    pub fn append_from(&mut self, mut other: ParallelVec<Param, A>) {
+        self.append(&mut other);
+    }
+
     /// Appends an element to the back of a collection.
     pub fn push(&mut self, value: Param) {
+        debug_assert!(self.len <= self.capacity, "length exceeds capacity");
         unsafe {
             self.reserve(1);
             let ptr = Param::ptr_at(self.storage, self.len);
@@ -163,19 +471,43 @@ impl<Param: ParallelParam> ParallelVec<Param> {
     /// [`remove`]: Self::remove
     pub fn swap_remove(&mut self, index: usize) -> Param {
         assert_in_bounds(index, self.len);
+        unsafe { self.swap_remove_unchecked(index) }
+    }
 
+    /// Calls `f` with references to the element at `index`, then removes the element
+    /// from the vector and returns both the result of `f` and the element.
+    ///
+    /// The removed element is replaced by the last element of the vector, like
+    /// [`swap_remove`]. If `f` panics, the vector is left unchanged.
+    ///
+    /// # Panics
+    /// This function will panic if `index >= self.len`.
+    ///
+    /// [`swap_remove`]: Self::swap_remove
+    pub fn swap_remove_with<R, F>(&mut self, index: usize, f: F) -> (R, Param)
+    where
+        F: FnOnce(Param::Ref<'_>) -> R,
+    {
+        assert_in_bounds(index, self.len);
         unsafe {
-            let target_ptr = Param::ptr_at(self.storage, index);
-            let value = Param::read(target_ptr);
-            self.len -= 1;
+            let result = f(Param::as_ref(Param::ptr_at(self.storage, index)));
+            (result, self.swap_remove_unchecked(index))
+        }
+    }
 
-            if self.len != index {
-                let end = Param::ptr_at(self.storage, self.len);
-                Param::copy_to_nonoverlapping(end, target_ptr, 1);
-            }
+    /// # Safety
+    /// `index` must be less than `self.len`.
+    unsafe fn swap_remove_unchecked(&mut self, index: usize) -> Param {
+        let target_ptr = Param::ptr_at(self.storage, index);
+        let value = Param::read(target_ptr);
+        self.len -= 1;
 
-            value
+        if self.len != index {
+            let end = Param::ptr_at(self.storage, self.len);
+            Param::copy_to_nonoverlapping(end, target_ptr, 1);
         }
+
+        value
     }
 
     /// Inserts a value at `index`. Moves all of the elements above
@@ -201,6 +533,9 @@ impl<Param: ParallelParam> ParallelVec<Param> {
     /// Removes a value at `index`. Moves all of the elements above
     /// `index` down one index. This is a `O(N)` operation.
     ///
+    /// Removing the last element does not move any other elements, and is
+    /// equivalent to [`pop`](Self::pop).
+    ///
     /// Returns `None` if `index` is is greater than or equal to `len()`.
     pub fn remove(&mut self, index: usize) -> Option<Param> {
         if index >= self.len {
@@ -215,534 +550,3259 @@ impl<Param: ParallelParam> ParallelVec<Param> {
         }
     }
 
-    /// Reserves capacity for at least `additional` more elements to be inserted in the
-    /// given [`ParallelVec`]. The collection may reserve more space to avoid frequent
-    /// reallocations. After calling reserve, capacity will be greater than or
-    /// equal to `self.len() + additional`. Does nothing if capacity is already
-    /// sufficient.
-    pub fn reserve(&mut self, additional: usize) {
-        unsafe {
-            let new_capacity = self.len.checked_add(additional).expect("capacity overflow");
-            if new_capacity > self.capacity {
-                let capacity = new_capacity.next_power_of_two().max(4);
-                debug_assert!(capacity > self.len, "capacity overflow");
-                self.storage = Param::realloc(self.storage, self.capacity, capacity);
-                self.capacity = capacity;
-            }
+    /// Gets the entry for `key` in a vector sorted by the key extracted with `f`, for
+    /// in-place manipulation.
+    ///
+    /// The entry is located with [`binary_search_by_key`]. If the vector is not sorted
+    /// by the extracted key, the returned entry is unspecified and meaningless.
+    ///
+    /// [`binary_search_by_key`]: ParallelSliceMut::binary_search_by_key
+    pub fn entry_by_key<K, F>(&mut self, key: K, f: F) -> Entry<'_, Param, A>
+    where
+        F: FnMut(Param::Ref<'_>) -> K,
+        K: Ord,
+    {
+        match self.binary_search_by_key(&key, f) {
+            Ok(index) => Entry::Occupied(OccupiedEntry { vec: self, index }),
+            Err(index) => Entry::Vacant(VacantEntry { vec: self, index }),
         }
     }
-}
 
-impl<Param: ParallelParam + Copy> ParallelVec<Param> {
-    /// Creates a [`ParallelVec`] by repeating `self` `n` times.
-    pub fn repeat(&self, n: usize) -> ParallelVec<Param> {
-        let mut new = ParallelVec::with_capacity(n * self.len);
-        let mut dst = Param::as_ptr(new.storage);
-        new.len = n * self.len;
-        unsafe {
-            let base = Param::as_ptr(self.storage);
-            for _ in 0..n {
-                for idx in 0..self.len {
-                    let value = Param::read(Param::add(base, idx));
-                    Param::write(dst, value);
-                    dst = Param::add(dst, 1);
-                }
-            }
-        }
-        new
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all elements `e` for which `f(e)` returns `false`.
+    /// This method operates in place, visiting each element exactly once in the
+    /// original order, and preserves the order of the retained elements.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Param::Ref<'_>) -> bool,
+    {
+        self.retain_ptr(|ptr| f(unsafe { Param::as_ref(ptr) }));
     }
-}
 
-impl<Param: ParallelParam> Drop for ParallelVec<Param> {
-    fn drop(&mut self) {
-        let end = self.len;
-        // Set len to 0 first in case one of the Drop impls panics
-        self.len = 0;
-        unsafe {
-            self.drop_range(0, end);
-            Param::dealloc(self.storage, self.capacity);
+    /// Retains only the elements specified by the predicate, then shrinks the
+    /// vector's capacity to fit if fewer than a quarter of it is still in use.
+    ///
+    /// This behaves like [`retain`], which never changes the capacity. It is
+    /// useful for long-lived vectors where a single large removal would otherwise
+    /// keep a mostly empty allocation alive.
+    ///
+    /// [`retain`]: Self::retain
+    pub fn retain_and_shrink<F>(&mut self, f: F)
+    where
+        F: FnMut(Param::Ref<'_>) -> bool,
+    {
+        self.retain(f);
+        if self.len < self.capacity / 4 {
+            self.shrink_to_fit();
         }
     }
-}
 
-impl<Param: ParallelParam> From<Vec<Param>> for ParallelVec<Param> {
-    fn from(value: Vec<Param>) -> Self {
-        Self::from_iter(value.into_iter())
+    /// Retains only the elements specified by the predicate, passing a mutable
+    /// reference to it.
+    ///
+    /// In other words, remove all elements `e` such that `f(e)` returns `false`.
+    /// This method operates in place, visiting each element exactly once in the
+    /// original order, and preserves the order of the retained elements.
+    ///
+    /// If `f` panics, every element is either dropped or left in the vector
+    /// exactly once.
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Param::RefMut<'_>) -> bool,
+    {
+        self.retain_ptr(|ptr| f(unsafe { Param::as_mut(ptr) }));
     }
-}
 
-impl<'a, Param: ParallelParam> PartialEq for ParallelVec<Param>
-where
-    Param: 'a,
-    Param::Ref<'a>: PartialEq,
-{
-    fn eq(&self, other: &Self) -> bool {
-        if self.len != other.len {
-            return false;
+    fn retain_ptr<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Param::Ptr) -> bool,
+    {
+        struct BackshiftOnDrop<'a, Param: ParallelParam, A: Allocator> {
+            vec: &'a mut ParallelVec<Param, A>,
+            processed: usize,
+            deleted: usize,
+            original_len: usize,
         }
-        if self.storage == other.storage {
-            // Pointing to the same storage. Shortcut out.
-            return true;
+
+        impl<'a, Param: ParallelParam, A: Allocator> Drop for BackshiftOnDrop<'a, Param, A> {
+            fn drop(&mut self) {
+                if self.deleted > 0 {
+                    // SAFE: Trailing unchecked items must be valid since we never touch them.
+                    unsafe {
+                        let src = Param::ptr_at(self.vec.storage, self.processed);
+                        let dst = Param::ptr_at(self.vec.storage, self.processed - self.deleted);
+                        Param::copy_to(src, dst, self.original_len - self.processed);
+                    }
+                }
+                self.vec.len = self.original_len - self.deleted;
+            }
         }
-        self.iter().zip(other.iter()).all(|(a, b)| a.eq(&b))
-    }
-}
 
-impl<'a, Param: ParallelParam> Eq for ParallelVec<Param>
-where
-    Param: 'a,
-    Param::Ref<'a>: Eq,
-{
-}
+        let original_len = self.len;
+        // Avoid double drops if the guard is not executed, since holes may be
+        // made during the process.
+        self.len = 0;
+        let mut guard = BackshiftOnDrop {
+            vec: self,
+            processed: 0,
+            deleted: 0,
+            original_len,
+        };
 
-impl<'a, Param: ParallelParam> Debug for ParallelVec<Param>
-where
-    Param: 'a,
-    Param::Ref<'a>: Debug,
-{
-    fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
-        fmt.write_str("ParallelVec")?;
-        fmt.debug_list().entries(self.iter()).finish()
+        while guard.processed != original_len {
+            unsafe {
+                let cur = Param::ptr_at(guard.vec.storage, guard.processed);
+                if !f(cur) {
+                    // Advance early to avoid double drop if the drop panics.
+                    guard.processed += 1;
+                    guard.deleted += 1;
+                    Param::drop(cur);
+                    continue;
+                }
+                if guard.deleted > 0 {
+                    let hole = Param::ptr_at(guard.vec.storage, guard.processed - guard.deleted);
+                    Param::copy_to_nonoverlapping(cur, hole, 1);
+                }
+                guard.processed += 1;
+            }
+        }
     }
-}
 
-impl<'a, Param: ParallelParam> Hash for ParallelVec<Param>
-where
-    Param: 'a,
-    Param::Ref<'a>: Hash,
-{
-    fn hash<H>(&self, hasher: &mut H)
+    /// Removes all but the first of consecutive elements in the vector satisfying a given
+    /// equality relation.
+    ///
+    /// The `same` function is passed references to two elements from the vector and
+    /// must determine if the elements compare equal. The elements are passed in opposite
+    /// order from their order in the slice, so if `same(a, b)` returns `true`, `a` is
+    /// removed.
+    ///
+    /// If the vector is sorted, this removes all duplicates.
+    pub fn dedup_by<F>(&mut self, mut same: F)
     where
-        H: Hasher,
+        F: FnMut(Param::RefMut<'_>, Param::RefMut<'_>) -> bool,
     {
-        self.deref().hash(hasher);
+        self.dedup_by_ptr(
+            |a, b| unsafe { same(Param::as_mut(a), Param::as_mut(b)) },
+            |ptr| unsafe { Param::drop(ptr) },
+        );
     }
-}
 
-impl<Param: ParallelParam> FromIterator<Param> for ParallelVec<Param> {
-    fn from_iter<T>(iter: T) -> Self
+    /// Removes all but the first of consecutive elements in the vector that resolve to
+    /// the same key.
+    ///
+    /// If the vector is sorted, this removes all duplicates.
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
     where
-        T: IntoIterator<Item = Param>,
+        F: FnMut(Param::RefMut<'_>) -> K,
+        K: PartialEq,
     {
-        let iter = iter.into_iter();
-        let (min, _) = iter.size_hint();
-        let mut parallel_vec = Self::with_capacity(min);
-        for item in iter {
-            parallel_vec.push(item);
-        }
-        parallel_vec
-    }
-}
-
-impl<Param: ParallelParam> IntoIterator for ParallelVec<Param> {
-    type Item = Param;
-    type IntoIter = IntoIter<Param>;
-    fn into_iter(self) -> Self::IntoIter {
-        let iter = IntoIter {
-            storage: self.storage,
-            capacity: self.capacity,
-            len: self.len,
-            idx: 0,
-        };
-        core::mem::forget(self);
-        iter
+        self.dedup_by(|a, b| key(a) == key(b));
     }
-}
 
-impl<Param: ParallelParam> Extend<Param> for ParallelVec<Param> {
-    fn extend<T>(&mut self, iter: T)
+    /// Removes consecutive repeated elements in the vector.
+    ///
+    /// If the vector is sorted, this removes all duplicates.
+    pub fn dedup(&mut self)
     where
-        T: IntoIterator<Item = Param>,
+        for<'b> Param::Ref<'b>: PartialEq,
     {
-        let iterator = iter.into_iter();
-        let (min, _) = iterator.size_hint();
-        self.reserve(min);
-        for param in iterator {
-            self.push(param);
-        }
+        self.dedup_by_ptr(
+            |a, b| unsafe { Param::as_ref(a) == Param::as_ref(b) },
+            |ptr| unsafe { Param::drop(ptr) },
+        );
     }
-}
 
-impl<Param: ParallelParam + Clone> Clone for ParallelVec<Param> {
-    fn clone(&self) -> Self {
-        let mut clone = Self::with_capacity(self.len);
+    /// Removes all but the first of consecutive elements in the vector satisfying a given
+    /// equality relation, moving the removed elements to the end of `sink` instead of
+    /// dropping them.
+    ///
+    /// The removed elements are moved into `sink` in the order they were removed. See
+    /// [`dedup_by`] for the semantics of `same`.
+    ///
+    /// [`dedup_by`]: Self::dedup_by
+    pub fn dedup_into<F>(&mut self, mut same: F, sink: &mut ParallelVec<Param, A>)
+    where
+        F: FnMut(Param::RefMut<'_>, Param::RefMut<'_>) -> bool,
+    {
+        self.dedup_by_ptr(
+            |a, b| unsafe { same(Param::as_mut(a), Param::as_mut(b)) },
+            |ptr| sink.push(unsafe { Param::read(ptr) }),
+        );
+    }
+
+    /// Removes all but the first of consecutive elements for which `same` returns `true`,
+    /// passing the pointers to the removed elements to `remove`, which takes ownership
+    /// of them.
+    fn dedup_by_ptr<F, R>(&mut self, mut same: F, mut remove: R)
+    where
+        F: FnMut(Param::Ptr, Param::Ptr) -> bool,
+        R: FnMut(Param::Ptr),
+    {
+        struct FillGapOnDrop<'a, Param: ParallelParam, A: Allocator> {
+            vec: &'a mut ParallelVec<Param, A>,
+            read: usize,
+            write: usize,
+            original_len: usize,
+        }
+
+        impl<'a, Param: ParallelParam, A: Allocator> Drop for FillGapOnDrop<'a, Param, A> {
+            fn drop(&mut self) {
+                // SAFE: Items in `read..original_len` have not been visited and are still valid.
+                unsafe {
+                    let src = Param::ptr_at(self.vec.storage, self.read);
+                    let dst = Param::ptr_at(self.vec.storage, self.write);
+                    let remaining = self.original_len - self.read;
+                    Param::copy_to(src, dst, remaining);
+                    self.vec.len = self.write + remaining;
+                }
+            }
+        }
+
+        let original_len = self.len;
+        if original_len <= 1 {
+            return;
+        }
+
+        // Avoid double drops if the guard is not executed, since holes may be
+        // made during the process.
+        self.len = 0;
+        let mut guard = FillGapOnDrop {
+            vec: self,
+            read: 1,
+            write: 1,
+            original_len,
+        };
+
         unsafe {
-            let base = Param::as_ptr(self.storage);
+            while guard.read < original_len {
+                let cur = Param::ptr_at(guard.vec.storage, guard.read);
+                let prev = Param::ptr_at(guard.vec.storage, guard.write - 1);
+                if same(cur, prev) {
+                    // Advance early to avoid double drop if `remove` panics.
+                    guard.read += 1;
+                    remove(cur);
+                } else {
+                    if guard.read != guard.write {
+                        let dst = Param::ptr_at(guard.vec.storage, guard.write);
+                        Param::copy_to_nonoverlapping(cur, dst, 1);
+                    }
+                    guard.write += 1;
+                    guard.read += 1;
+                }
+            }
+        }
+    }
+
+    /// Resizes the vector in-place so that `len` is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than `len`, the vector is extended by the difference,
+    /// with each additional slot filled with the result of calling the closure `f`.
+    ///
+    /// If `new_len` is less than `len`, the vector is simply truncated. This never
+    /// reallocates or changes the capacity of the vector.
+    pub fn resize_with<F: FnMut() -> Param>(&mut self, new_len: usize, mut f: F) {
+        self.resize_with_indexed(new_len, |_| f());
+    }
+
+    /// Resizes the vector in-place so that `len` is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than `len`, the vector is extended by the difference,
+    /// with each additional slot filled with the result of calling the closure `f`
+    /// with the index of the slot.
+    ///
+    /// If `new_len` is less than `len`, the vector is simply truncated. This never
+    /// reallocates or changes the capacity of the vector.
+    pub fn resize_with_indexed<F: FnMut(usize) -> Param>(&mut self, new_len: usize, mut f: F) {
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
+        }
+        self.reserve(new_len - self.len);
+        unsafe {
+            while self.len < new_len {
+                Param::write(Param::ptr_at(self.storage, self.len), f(self.len));
+                self.len += 1;
+            }
+        }
+    }
+
+    /// Appends `count` rows to the end of the vector, each generated by calling `f`
+    /// with the index the row will have in the vector, like
+    /// [`resize_with_indexed`](Self::resize_with_indexed).
+    ///
+    /// Space for all of the rows is reserved up front and the length is only
+    /// updated once all of them have been written. If `f` panics, the rows
+    /// generated so far are kept in the vector.
+    pub fn append_n_with<F: FnMut(usize) -> Param>(&mut self, count: usize, mut f: F) {
+        struct SetLenOnDrop<'a, Param: ParallelParam, A: Allocator> {
+            vec: &'a mut ParallelVec<Param, A>,
+            len: usize,
+        }
+
+        impl<'a, Param: ParallelParam, A: Allocator> Drop for SetLenOnDrop<'a, Param, A> {
+            fn drop(&mut self) {
+                self.vec.len = self.len;
+            }
+        }
+
+        self.reserve(count);
+        let start = self.len;
+        let mut guard = SetLenOnDrop {
+            vec: self,
+            len: start,
+        };
+        while guard.len < start + count {
+            // SAFE: The capacity was reserved above, and `guard.len` never exceeds
+            // `start + count`.
+            unsafe {
+                Param::write(Param::ptr_at(guard.vec.storage, guard.len), f(guard.len));
+            }
+            guard.len += 1;
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more elements to be inserted in the
+    /// given [`ParallelVec`]. The collection may reserve more space to avoid frequent
+    /// reallocations. After calling reserve, capacity will be greater than or
+    /// equal to `self.len() + additional`. Does nothing if capacity is already
+    /// sufficient.
+    ///
+    /// The capacity is rounded up to the next power of two while the vector's
+    /// allocations are small. Once they reach 4 MiB, the vector grows by a
+    /// factor of 1.5 instead, to avoid doubling very large buffers.
+    pub fn reserve(&mut self, additional: usize) {
+        debug_assert!(self.len <= self.capacity, "length exceeds capacity");
+        unsafe {
+            let new_capacity = self.len.checked_add(additional).expect("capacity overflow");
+            if new_capacity > self.capacity {
+                let capacity = self.grown_capacity(new_capacity);
+                self.storage =
+                    Param::realloc(self.storage, self.capacity, capacity, &self.allocator);
+                self.capacity = capacity;
+            }
+        }
+    }
+
+    /// Reserves the minimum capacity for exactly `additional` more elements to be
+    /// inserted in the given [`ParallelVec`]. After calling `reserve_exact`, capacity
+    /// will be greater than or equal to `self.len() + additional`. Does nothing if
+    /// the capacity is already sufficient.
+    ///
+    /// Unlike [`reserve`], this does not round the capacity up, so it is useful for
+    /// vectors that are known to stay small, such as many vectors holding a single
+    /// element. Prefer [`reserve`] if more insertions are expected.
+    ///
+    /// ```
+    /// use parallel_vec::ParallelVec;
+    ///
+    /// let mut vec = ParallelVec::new();
+    /// vec.reserve_exact(1);
+    /// vec.push((1u32, 2u64));
+    /// assert_eq!(vec.capacity(), 1);
+    /// ```
+    ///
+    /// [`reserve`]: Self::reserve
+    pub fn reserve_exact(&mut self, additional: usize) {
+        debug_assert!(self.len <= self.capacity, "length exceeds capacity");
+        unsafe {
+            let new_capacity = self.len.checked_add(additional).expect("capacity overflow");
+            if new_capacity > self.capacity {
+                self.storage =
+                    Param::realloc(self.storage, self.capacity, new_capacity, &self.allocator);
+                self.capacity = new_capacity;
+            }
+        }
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements to be inserted
+    /// in the given [`ParallelVec`]. Grows the same way as [`reserve`], but returns an
+    /// error instead of panicking or aborting if the capacity overflows or the
+    /// allocator reports a failure.
+    ///
+    /// If an error is returned, the vector is left unchanged.
+    ///
+    /// [`reserve`]: Self::reserve
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        debug_assert!(self.len <= self.capacity, "length exceeds capacity");
+        let new_capacity = self
+            .len
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        if new_capacity <= self.capacity {
+            return Ok(());
+        }
+        let capacity = self.grown_capacity(new_capacity);
+        unsafe {
+            // Acquire all of the new storage before touching `self`, so that a
+            // failure leaves the vector as it was.
+            let storage = Param::try_alloc(capacity, &self.allocator)?;
+            let src = Param::as_ptr(self.storage);
+            Param::copy_to_nonoverlapping(src, Param::as_ptr(storage), self.len);
+            Param::dealloc(self.storage, self.capacity, &self.allocator);
+            self.storage = storage;
+        }
+        self.capacity = capacity;
+        Ok(())
+    }
+
+    fn grown_capacity(&self, required: usize) -> usize {
+        let mut capacity = grow_capacity(self.capacity, required, Param::ROW_SIZE);
+        // Only round tiny first allocations up. Vectors that were explicitly
+        // given a small capacity keep growing from it.
+        if self.capacity == 0 {
+            capacity = capacity.max(4);
+        }
+        debug_assert!(capacity > self.len, "capacity overflow");
+        capacity
+    }
+}
+
+impl<Param: ParallelParam + Clone, A: Allocator + Clone> ParallelVec<Param, A> {
+    /// Clones the vector, preserving the capacity of `self` instead of only
+    /// allocating enough for its length.
+    ///
+    /// This is useful when the clone is expected to grow to a similar size as the
+    /// original, such as double-buffered data.
+    pub fn clone_with_capacity(&self) -> Self {
+        self.clone_into_capacity(self.capacity)
+    }
+
+    fn clone_into_capacity(&self, capacity: usize) -> Self {
+        debug_assert!(capacity >= self.len);
+        let mut clone = Self::with_capacity_in(capacity, self.allocator.clone());
+        unsafe {
+            let src = Param::as_ptr(self.storage);
+            let dst = Param::as_ptr(clone.storage);
             for idx in 0..self.len {
-                let value = Param::read(Param::add(base, idx));
-                clone.push(value.clone());
+                // The read value is still owned by `self` and must not be dropped.
+                let value = ManuallyDrop::new(Param::read(Param::add(src, idx)));
+                Param::write(Param::add(dst, idx), (*value).clone());
+                // Capacity is already reserved, so only the length needs to be updated.
+                // This is done every iteration so that a panicking clone does not leak.
+                clone.len = idx + 1;
             }
         }
         clone
     }
-}
+}
+
+impl<Param: ParallelParam, A: Allocator + Clone> ParallelVec<Param, A> {
+    /// Removes the last `n` elements from the vector and returns them in a new
+    /// vector, in their original order.
+    ///
+    /// If `n` is greater than the vector's length, every element is removed.
+    /// This moves the elements in bulk, and is more efficient than calling
+    /// [`pop`] `n` times.
+    ///
+    /// [`pop`]: Self::pop
+    pub fn pop_n(&mut self, n: usize) -> ParallelVec<Param, A> {
+        let n = n.min(self.len);
+        let mut popped = Self::with_capacity_in(n, self.allocator.clone());
+        unsafe {
+            let start = self.len - n;
+            let src = Param::ptr_at(self.storage, start);
+            Param::copy_to_nonoverlapping(src, Param::as_ptr(popped.storage), n);
+            // The moved values are now owned by `popped`.
+            self.len = start;
+            popped.len = n;
+        }
+        popped
+    }
+
+    /// Removes the elements at every index in `indices` from the vector using
+    /// [`swap_remove`], and returns them in a new vector.
+    ///
+    /// The indices refer to positions in the vector before any element is removed.
+    /// `indices` is sorted in descending order, and the elements are removed from
+    /// the highest index to the lowest, so each removal leaves the remaining indices
+    /// valid. The removed elements are returned in that order. Duplicate indices
+    /// are only removed once.
+    ///
+    /// # Panics
+    /// This function will panic if any index is out of bounds. The vector is left
+    /// unchanged if it panics.
+    ///
+    /// [`swap_remove`]: Self::swap_remove
+    pub fn swap_remove_many(&mut self, indices: &mut [usize]) -> ParallelVec<Param, A> {
+        indices.sort_unstable_by(|a, b| b.cmp(a));
+        if let Some(max) = indices.first() {
+            assert_in_bounds(*max, self.len);
+        }
+        let mut removed = ParallelVec::with_capacity_in(indices.len(), self.allocator.clone());
+        let mut previous = None;
+        for &index in indices.iter() {
+            if previous == Some(index) {
+                continue;
+            }
+            previous = Some(index);
+            // SAFE: The largest index was checked above, and every index removed
+            // so far was larger than this one.
+            let value = unsafe { self.swap_remove_unchecked(index) };
+            removed.push(value);
+        }
+        removed
+    }
+}
+
+impl<Param: ParallelParam + Clone, A: Allocator> ParallelVec<Param, A> {
+    /// Resizes the vector in-place so that `len` is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than `len`, the vector is extended by the difference,
+    /// with each additional slot filled with clones of `value`.
+    ///
+    /// If `new_len` is less than `len`, the vector is simply truncated. This never
+    /// reallocates or changes the capacity of the vector.
+    pub fn resize(&mut self, new_len: usize, value: Param) {
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
+        }
+        self.reserve(new_len - self.len);
+        unsafe {
+            while self.len < new_len - 1 {
+                Param::write(Param::ptr_at(self.storage, self.len), value.clone());
+                self.len += 1;
+            }
+            Param::write(Param::ptr_at(self.storage, self.len), value);
+            self.len += 1;
+        }
+    }
+
+    /// Inserts `n` copies of `value` at `index`. Moves all of the elements above
+    /// `index` up `n` indices. This is a `O(N)` operation, but the elements are only
+    /// moved once, unlike calling [`insert`](Self::insert) `n` times.
+    ///
+    /// If a clone of `value` panics, the copies written so far are kept and the
+    /// elements above `index` are moved back down next to them.
+    ///
+    /// # Panics
+    /// This function will panic if `index` is greater than `len()`.
+    pub fn insert_n(&mut self, index: usize, value: Param, n: usize) {
+        struct CloseGapOnDrop<'a, Param: ParallelParam, A: Allocator> {
+            vec: &'a mut ParallelVec<Param, A>,
+            index: usize,
+            filled: usize,
+            gap: usize,
+            tail: usize,
+        }
+
+        impl<'a, Param: ParallelParam, A: Allocator> Drop for CloseGapOnDrop<'a, Param, A> {
+            fn drop(&mut self) {
+                // SAFE: The tail was moved to `index + gap` and is still valid.
+                unsafe {
+                    if self.filled != self.gap {
+                        let src = Param::ptr_at(self.vec.storage, self.index + self.gap);
+                        let dst = Param::ptr_at(self.vec.storage, self.index + self.filled);
+                        Param::copy_to(src, dst, self.tail);
+                    }
+                }
+                self.vec.len = self.index + self.filled + self.tail;
+            }
+        }
+
+        if index > self.len {
+            out_of_bounds(index, self.len);
+        }
+        if n == 0 {
+            return;
+        }
+        self.reserve(n);
+        let tail = self.len - index;
+        // Avoid double drops if the guard is not executed, since a gap is made in
+        // the middle of the vector.
+        self.len = index;
+        unsafe {
+            let ptr = Param::ptr_at(self.storage, index);
+            Param::copy_to(ptr, Param::add(ptr, n), tail);
+        }
+        let mut guard = CloseGapOnDrop {
+            vec: self,
+            index,
+            filled: 0,
+            gap: n,
+            tail,
+        };
+        unsafe {
+            while guard.filled < n - 1 {
+                let ptr = Param::ptr_at(guard.vec.storage, index + guard.filled);
+                Param::write(ptr, value.clone());
+                guard.filled += 1;
+            }
+            Param::write(
+                Param::ptr_at(guard.vec.storage, index + guard.filled),
+                value,
+            );
+            guard.filled += 1;
+        }
+    }
+
+    /// Clones the elements from the `src` range and appends them to the end of
+    /// the vector.
+    ///
+    /// # Panics
+    /// This function will panic if the range is out of bounds.
+    pub fn extend_from_within<R: RangeBounds<usize>>(&mut self, src: R) {
+        let range = to_range(src, self.len);
+        // Reserve before taking any pointers, as growing the vector may
+        // reallocate the storage.
+        self.reserve(range.len());
+        unsafe {
+            let base = Param::as_ptr(self.storage);
+            for idx in range {
+                // The read value is still owned by `self` and must not be dropped.
+                let value = ManuallyDrop::new(Param::read(Param::add(base, idx)));
+                Param::write(Param::add(base, self.len), (*value).clone());
+                self.len += 1;
+            }
+        }
+    }
+}
+
+impl<Param: ParallelParam + Copy, A: Allocator + Clone> ParallelVec<Param, A> {
+    /// Creates a [`ParallelVec`] by repeating `self` `n` times.
+    ///
+    /// # Panics
+    /// This function will panic if the length of the result would overflow.
+    pub fn repeat(&self, n: usize) -> ParallelVec<Param, A> {
+        let len = n.checked_mul(self.len).expect("capacity overflow");
+        let mut new = ParallelVec::with_capacity_in(len, self.allocator.clone());
+        let mut dst = Param::as_ptr(new.storage);
+        new.len = len;
+        unsafe {
+            let base = Param::as_ptr(self.storage);
+            for _ in 0..n {
+                for idx in 0..self.len {
+                    let value = Param::read(Param::add(base, idx));
+                    Param::write(dst, value);
+                    dst = Param::add(dst, 1);
+                }
+            }
+        }
+        new
+    }
+}
+
+// SAFE: ParallelVec owns its allocation and the values within it, just like
+// Vec<T>. It is only Send/Sync if all of the stored values are as well.
+unsafe impl<Param: ParallelParam + Send, A: Allocator + Send> Send for ParallelVec<Param, A> {}
+unsafe impl<Param: ParallelParam + Sync, A: Allocator + Sync> Sync for ParallelVec<Param, A> {}
+
+impl<Param: ParallelParam, A: Allocator> Drop for ParallelVec<Param, A> {
+    fn drop(&mut self) {
+        struct DeallocOnDrop<'a, Param: ParallelParam, A: Allocator> {
+            storage: Param::Storage,
+            capacity: usize,
+            allocator: &'a A,
+        }
+
+        impl<'a, Param: ParallelParam, A: Allocator> Drop for DeallocOnDrop<'a, Param, A> {
+            fn drop(&mut self) {
+                // SAFE: The storage is only deallocated here, exactly once, even if
+                // one of the elements' Drop impls panics.
+                unsafe {
+                    Param::dealloc(self.storage, self.capacity, self.allocator);
+                }
+            }
+        }
+
+        let end = self.len;
+        // Set len to 0 first in case one of the Drop impls panics
+        self.len = 0;
+        let _guard = DeallocOnDrop::<Param, A> {
+            storage: self.storage,
+            capacity: self.capacity,
+            allocator: &self.allocator,
+        };
+        unsafe {
+            Param::drop_range(Param::as_ptr(self.storage), end);
+        }
+    }
+}
+
+impl<Param: ParallelParam> From<Vec<Param>> for ParallelVec<Param> {
+    fn from(value: Vec<Param>) -> Self {
+        Self::from_aos(value)
+    }
+}
+
+impl<Param: ParallelParam, const N: usize> From<[Param; N]> for ParallelVec<Param> {
+    fn from(value: [Param; N]) -> Self {
+        let mut vec = Self::with_capacity(N);
+        unsafe {
+            for (idx, param) in value.into_iter().enumerate() {
+                Param::write(Param::ptr_at(vec.storage, idx), param);
+            }
+        }
+        vec.len = N;
+        vec
+    }
+}
+
+impl<'a, Param: ParallelParam, A: Allocator> PartialEq for ParallelVec<Param, A>
+where
+    Param: 'a,
+    Param::Ref<'a>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        if self.len != other.len {
+            return false;
+        }
+        if self.storage == other.storage {
+            // Pointing to the same storage. Shortcut out.
+            return true;
+        }
+        self.iter().zip(other.iter()).all(|(a, b)| a.eq(&b))
+    }
+}
+
+impl<'a, Param: ParallelParam, A: Allocator> Eq for ParallelVec<Param, A>
+where
+    Param: 'a,
+    Param::Ref<'a>: Eq,
+{
+}
+
+/// Vectors are compared [lexicographically], row by row. A vector that is a prefix
+/// of another compares as less than it.
+///
+/// [lexicographically]: core::cmp::Ord#lexicographical-comparison
+impl<'a, Param: ParallelParam, A: Allocator> PartialOrd for ParallelVec<Param, A>
+where
+    Param: 'a,
+    Param::Ref<'a>: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+/// Vectors are compared [lexicographically], row by row. A vector that is a prefix
+/// of another compares as less than it.
+///
+/// [lexicographically]: core::cmp::Ord#lexicographical-comparison
+impl<'a, Param: ParallelParam, A: Allocator> Ord for ParallelVec<Param, A>
+where
+    Param: 'a,
+    Param::Ref<'a>: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<'a, Param: ParallelParam, A: Allocator> Debug for ParallelVec<Param, A>
+where
+    Param: 'a,
+    Param::Ref<'a>: Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+        fmt.write_str("ParallelVec")?;
+        fmt.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, Param: ParallelParam, A: Allocator> Hash for ParallelVec<Param, A>
+where
+    Param: 'a,
+    Param::Ref<'a>: Hash,
+{
+    fn hash<H>(&self, hasher: &mut H)
+    where
+        H: Hasher,
+    {
+        self.deref().hash(hasher);
+    }
+}
+
+impl<Param: ParallelParam> FromIterator<Param> for ParallelVec<Param> {
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = Param>,
+    {
+        let mut iter = iter.into_iter();
+        let (min, _) = iter.size_hint();
+        let mut parallel_vec = Self::with_capacity(min);
+        // Fast path: the first `min` items are guaranteed to fit in the
+        // allocation, so they can be written without checking the capacity.
+        // This covers all items of exact-size sources in a single allocation.
+        unsafe {
+            let base = Param::as_ptr(parallel_vec.storage);
+            while parallel_vec.len < min {
+                match iter.next() {
+                    Some(item) => {
+                        Param::write(Param::add(base, parallel_vec.len), item);
+                        parallel_vec.len += 1;
+                    }
+                    None => return parallel_vec,
+                }
+            }
+        }
+        for item in iter {
+            parallel_vec.push(item);
+        }
+        parallel_vec
+    }
+}
+
+impl<Param: ParallelParam> IntoIterator for ParallelVec<Param> {
+    type Item = Param;
+    type IntoIter = IntoIter<Param>;
+    fn into_iter(self) -> Self::IntoIter {
+        let iter = IntoIter {
+            storage: self.storage,
+            capacity: self.capacity,
+            len: self.len,
+            idx: 0,
+        };
+        core::mem::forget(self);
+        iter
+    }
+}
+
+impl<'a, Param: ParallelParam, A: Allocator> IntoIterator for &'a ParallelVec<Param, A> {
+    type Item = Param::Ref<'a>;
+    type IntoIter = Iter<'a, Param>;
+    fn into_iter(self) -> Self::IntoIter {
+        Iter {
+            ptr: Param::as_ptr(self.storage),
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, Param: ParallelParam, A: Allocator> IntoIterator for &'a mut ParallelVec<Param, A> {
+    type Item = Param::RefMut<'a>;
+    type IntoIter = IterMut<'a, Param>;
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut {
+            ptr: Param::as_ptr(self.storage),
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Param: ParallelParam, A: Allocator> Extend<Param> for ParallelVec<Param, A> {
+    fn extend<T>(&mut self, iter: T)
+    where
+        T: IntoIterator<Item = Param>,
+    {
+        let iterator = iter.into_iter();
+        let (min, _) = iterator.size_hint();
+        self.reserve(min);
+        for param in iterator {
+            self.push(param);
+        }
+    }
+}
+
+/// Extends the vector by moving every row out of each provided [`ParallelVec`].
+///
+/// Each vector's columns are moved in bulk as with [`append`], instead of
+/// reading and pushing one row at a time.
+///
+/// [`append`]: ParallelVec::append
+impl<Param: ParallelParam, A: Allocator> Extend<ParallelVec<Param, A>> for ParallelVec<Param, A> {
+    fn extend<T>(&mut self, iter: T)
+    where
+        T: IntoIterator<Item = ParallelVec<Param, A>>,
+    {
+        for other in iter {
+            self.append_from(other);
+        }
+    }
+}
+
+/// `Param` is a tuple, so `Param: Clone` holds exactly when every field is `Clone`.
+impl<Param: ParallelParam + Clone, A: Allocator + Clone> Clone for ParallelVec<Param, A> {
+    fn clone(&self) -> Self {
+        self.clone_into_capacity(self.len)
+    }
+}
+
+impl<Param: ParallelParam> Default for ParallelVec<Param> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Param: ParallelParam, A: Allocator> Deref for ParallelVec<Param, A> {
+    type Target = ParallelSliceMut<'static, Param>;
+    fn deref(&self) -> &Self::Target {
+        // SAFE: Both ParallelVec and ParallelSliceMut have the same
+        // layout in memory due to #[repr(C)]
+        unsafe {
+            let ptr: *const Self = self;
+            &*(ptr.cast::<Self::Target>())
+        }
+    }
+}
+
+impl<Param: ParallelParam, A: Allocator> DerefMut for ParallelVec<Param, A> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFE: Both ParallelVec and ParallelSliceMut have the same
+        // layout in memory due to #[repr(C)]
+        unsafe {
+            let ptr: *mut Self = self;
+            &mut *(ptr.cast::<Self::Target>())
+        }
+    }
+}
+
+/// The allocation size, in bytes, above which vectors stop doubling their capacity.
+const DOUBLING_LIMIT_BYTES: usize = 4 * 1024 * 1024;
+
+/// Computes the capacity to grow to from `capacity` to fit at least `required` rows
+/// of `row_size` bytes each, summed across all of the columns.
+fn grow_capacity(capacity: usize, required: usize, row_size: usize) -> usize {
+    if capacity.saturating_mul(row_size) < DOUBLING_LIMIT_BYTES {
+        required.checked_next_power_of_two().unwrap_or(required)
+    } else {
+        required.max(capacity.saturating_add(capacity / 2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParallelVec;
+    use crate::entry::Entry;
+    use crate::ParallelParam;
+    use std::cell::RefCell;
+    use std::convert::From;
+    use std::rc::Rc;
+    use std::string::String;
+    use std::vec::Vec;
+
+    #[allow(clippy::upper_case_acronyms)]
+    #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+    struct ZST;
+
+    #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+    struct ZST2;
+
+    #[test]
+    fn layouts_do_not_overlap() {
+        // Trying with both (small, large) and (large, small) to ensure nothing bleeds into anything else.
+        // This verifies we correctly chunk the slices from the larger allocations.
+        let mut vec_ab = ParallelVec::new();
+        let mut vec_ba = ParallelVec::new();
+
+        fn ab(v: usize) -> (u8, f64) {
+            (v as u8, 200.0 + ((v as f64) / 200.0))
+        }
+
+        fn ba(v: usize) -> (f64, u8) {
+            (15.0 + ((v as f64) / 16.0), (200 - v) as u8)
+        }
+
+        // Combined with the tests inside, also verifies that we are copying the data on grow correctly.
+        for i in 0..100 {
+            vec_ab.push(ab(i));
+            let (a, b) = vec_ab.as_slices();
+            assert_eq!(i + 1, a.len());
+            assert_eq!(i + 1, b.len());
+            assert_eq!(ab(0).0, a[0]);
+            assert_eq!(ab(0).1, b[0]);
+            assert_eq!(ab(i).0, a[i]);
+            assert_eq!(ab(i).1, b[i]);
+
+            vec_ba.push(ba(i));
+            let (b, a) = vec_ba.as_slices();
+            assert_eq!(i + 1, a.len());
+            assert_eq!(i + 1, b.len());
+            assert_eq!(ba(0).0, b[0]);
+            assert_eq!(ba(0).1, a[0]);
+            assert_eq!(ba(i).0, b[i]);
+            assert_eq!(ba(i).1, a[i]);
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let src: ParallelVec<(i32, i32, u64)> = ParallelVec::new();
+        assert_eq!(src.len(), 0);
+        assert_eq!(src.capacity(), 0);
+        assert!(src.is_empty());
+    }
+
+    #[test]
+    fn test_default() {
+        let src: ParallelVec<(i32, i32, u64)> = Default::default();
+        assert_eq!(src.len(), 0);
+        assert_eq!(src.capacity(), 0);
+        assert!(src.is_empty());
+    }
+
+    #[test]
+    fn test_with_capacity() {
+        let src: ParallelVec<(i32, i32, u64)> = ParallelVec::with_capacity(1000);
+        assert_eq!(src.len(), 0);
+        assert!(src.capacity() >= 1000);
+        assert!(src.is_empty());
+    }
+
+    #[test]
+    fn test_with_capacity_zst() {
+        let mut src: ParallelVec<(ZST, ZST2)> = ParallelVec::with_capacity(usize::MAX);
+        assert_eq!(src.len(), 0);
+        assert_eq!(src.capacity(), usize::MAX);
+        assert!(src.storage == <(ZST, ZST2) as ParallelParam>::dangling());
+        for _ in 0..10000 {
+            src.push((ZST, ZST2));
+        }
+        assert_eq!(src.len(), 10000);
+        assert_eq!(src.capacity(), usize::MAX);
+        assert!(src.storage == <(ZST, ZST2) as ParallelParam>::dangling());
+        assert_eq!(src.index(9999), (&ZST, &ZST2));
+    }
+
+    #[test]
+    fn test_reserve() {
+        let mut src = ParallelVec::new();
+        src.push((0, 0, 0, 0));
+        assert_eq!(src.len(), 1);
+        assert!(src.capacity() >= 1);
+        src.reserve(10);
+        assert_eq!(src.len(), 1);
+        assert!(src.capacity() >= 10);
+        src.reserve(100);
+        assert_eq!(src.len(), 1);
+        assert!(src.capacity() >= 100);
+        src.reserve(1000);
+        assert_eq!(src.len(), 1);
+        assert!(src.capacity() >= 1000);
+        src.reserve(100000);
+        assert_eq!(src.len(), 1);
+        assert!(src.capacity() >= 10000);
+    }
+
+    #[test]
+    fn test_clone() {
+        let mut src = ParallelVec::new();
+        src.push((1.0, 2.0));
+        src.push((3.0, 4.0));
+
+        let dst = src.clone();
+        assert_eq!(dst.len(), 2);
+        assert_eq!(dst.index(0), (&1.0, &2.0));
+        assert_eq!(dst.index(1), (&3.0, &4.0));
+    }
+
+    #[test]
+    fn test_clone_drops() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        src.extend(vec![(rc.clone(), 1), (rc.clone(), 2), (rc.clone(), 3)]);
+        let dst = src.clone();
+        assert_eq!(Rc::strong_count(&rc), 7);
+        assert_eq!(dst.len(), 3);
+        assert_eq!(dst.capacity(), 3);
+        assert_eq!(dst.column::<1>(), &[1, 2, 3]);
+        core::mem::drop(src);
+        assert_eq!(Rc::strong_count(&rc), 4);
+        core::mem::drop(dst);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn test_works_with_zsts() {
+        let mut src = ParallelVec::new();
+        src.push((1, ZST, 20u64, ZST2));
+        src.push((1, ZST, 21u64, ZST2));
+        src.push((1, ZST, 22u64, ZST2));
+        src.push((1, ZST, 23u64, ZST2));
+        assert_eq!(src.index(0), (&1, &ZST, &20u64, &ZST2));
+        assert_eq!(src.index(1), (&1, &ZST, &21u64, &ZST2));
+        assert_eq!(src.index(2), (&1, &ZST, &22u64, &ZST2));
+        assert_eq!(src.index(3), (&1, &ZST, &23u64, &ZST2));
+        assert_eq!(src.len(), 4);
+    }
+
+    #[test]
+    fn test_push() {
+        let mut src = ParallelVec::new();
+        src.push((1, 2));
+        assert_eq!(src.index(0), (&1, &2));
+        assert_eq!(src.len(), 1);
+    }
+
+    #[test]
+    fn test_pop() {
+        let mut src = ParallelVec::new();
+        src.push((1, 2));
+        src.push((3, 4));
+        src.push((5, 6));
+        src.push((7, 8));
+        assert_eq!(src.len(), 4);
+        let value = src.pop();
+        assert_eq!(value, Some((7, 8)));
+        assert_eq!(src.len(), 3);
+        let value = src.pop();
+        assert_eq!(value, Some((5, 6)));
+        assert_eq!(src.len(), 2);
+        let value = src.pop();
+        assert_eq!(value, Some((3, 4)));
+        assert_eq!(src.len(), 1);
+        let value = src.pop();
+        assert_eq!(value, Some((1, 2)));
+        assert_eq!(src.len(), 0);
+        let value = src.pop();
+        assert_eq!(value, None);
+        assert_eq!(src.len(), 0);
+        let value = src.pop();
+        assert_eq!(value, None);
+        assert_eq!(src.len(), 0);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut src = ParallelVec::new();
+        src.insert(0, (1, 2));
+        src.insert(0, (3, 4));
+        src.insert(1, (4, 5));
+        assert_eq!(src.index(0), (&3, &4));
+        assert_eq!(src.index(1), (&4, &5));
+        assert_eq!(src.index(2), (&1, &2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_insert_panics() {
+        let mut src = ParallelVec::new();
+        src.insert(0, (1, 2));
+        src.insert(0, (3, 4));
+        src.insert(1, (4, 5));
+        src.insert(20, (4, 5));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut src = ParallelVec::new();
+        src.push((1, 2));
+        src.push((3, 4));
+        assert_eq!(src.remove(1), Some((3, 4)));
+        assert_eq!(src.remove(0), Some((1, 2)));
+        assert_eq!(src.len(), 0);
+        assert_eq!(src.remove(5), None);
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut src = ParallelVec::new();
+        src.push((1, 2));
+        src.push((3, 4));
+        src.push((5, 6));
+        src.push((7, 8));
+        let mut iter = src.iter();
+        assert_eq!(iter.next(), Some((&1, &2)));
+        assert_eq!(iter.next(), Some((&3, &4)));
+        assert_eq!(iter.next(), Some((&5, &6)));
+        assert_eq!(iter.next(), Some((&7, &8)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        // Shouldn't have removed any of them
+        assert_eq!(src.len(), 4);
+    }
+
+    #[test]
+    fn test_iter_clone() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8), (9, 10)]);
+        let mut iter = src.iter();
+        assert_eq!(iter.next(), Some((&1, &2)));
+        let mut clone = iter.clone();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(clone.len(), 4);
+        assert_eq!(clone.next_back(), Some((&9, &10)));
+        assert_eq!(clone.next_back(), Some((&7, &8)));
+        assert_eq!(clone.len(), 2);
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some((&3, &4)));
+        assert_eq!(iter.next(), Some((&5, &6)));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(clone.len(), 2);
+        assert_eq!(clone.next(), Some((&3, &4)));
+        assert_eq!(clone.next(), Some((&5, &6)));
+        assert_eq!(clone.next(), None);
+        assert_eq!(iter.next_back(), Some((&9, &10)));
+        assert_eq!(iter.next(), Some((&7, &8)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_iters() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8), (9, 10), (11, 12)]);
+        let (mut a, mut b) = src.iters();
+        assert_eq!(a.next(), Some(&1));
+        assert_eq!(a.next(), Some(&3));
+        assert_eq!(a.next(), Some(&5));
+        assert_eq!(a.next(), Some(&7));
+        assert_eq!(a.next(), Some(&9));
+        assert_eq!(a.next(), Some(&11));
+        assert_eq!(a.next(), None);
+        assert_eq!(a.next(), None);
+        assert_eq!(b.next(), Some(&2));
+        assert_eq!(b.next(), Some(&4));
+        assert_eq!(b.next(), Some(&6));
+        assert_eq!(b.next(), Some(&8));
+        assert_eq!(b.next(), Some(&10));
+        assert_eq!(b.next(), Some(&12));
+        assert_eq!(b.next(), None);
+        assert_eq!(b.next(), None);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut src = ParallelVec::new();
+        src.push((1, 2));
+        src.push((3, 4));
+        src.push((5, 6));
+        src.push((7, 8));
+        let mut iter = src.iter_mut();
+        assert_eq!(iter.next(), Some((&mut 1, &mut 2)));
+        assert_eq!(iter.next(), Some((&mut 3, &mut 4)));
+        assert_eq!(iter.next(), Some((&mut 5, &mut 6)));
+        assert_eq!(iter.next(), Some((&mut 7, &mut 8)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        // Shouldn't have removed any of them
+        assert_eq!(src.len(), 4);
+    }
+
+    #[test]
+    fn test_iter_mut_step_by() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(0, 0), (1, 1), (2, 2), (3, 3), (4, 4)]);
+        let mut iter = src.iter_mut_step_by(2);
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some((&mut 0, &mut 0)));
+        assert_eq!(iter.next(), Some((&mut 2, &mut 2)));
+        let (a, _) = iter.next().unwrap();
+        *a = 40;
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[0, 1, 2, 3, 40]);
+        assert_eq!(b, &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_iter_mut_step_by_zero_panics() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(0, 0), (1, 1)]);
+        src.iter_mut_step_by(0);
+    }
+
+    #[test]
+    fn test_chunks_by() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 'a'), (1, 'b'), (2, 'c'), (2, 'd'), (3, 'e')]);
+        let mut chunks = src.chunks_by(|(a, _), (b, _)| a == b);
+        let chunk = chunks.next().unwrap();
+        assert_eq!(chunk.as_slices(), (&[1, 1][..], &['a', 'b'][..]));
+        let chunk = chunks.next().unwrap();
+        assert_eq!(chunk.as_slices(), (&[2, 2][..], &['c', 'd'][..]));
+        let chunk = chunks.next().unwrap();
+        assert_eq!(chunk.as_slices(), (&[3][..], &['e'][..]));
+        assert!(chunks.next().is_none());
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn test_chunks_by_empty() {
+        let src: ParallelVec<(i32, char)> = ParallelVec::new();
+        assert_eq!(src.chunks_by(|(a, _), (b, _)| a == b).count(), 0);
+    }
+
+    #[test]
+    fn test_iter_mut_rev() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        let mut visited = Vec::new();
+        for (idx, (a, _)) in src.iter_mut().rev().enumerate() {
+            visited.push(*a);
+            *a = idx as i32;
+        }
+        assert_eq!(visited, vec![7, 5, 3, 1]);
+        assert_eq!(src.column::<0>(), &[3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_iter_mut_interleaved() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8), (9, 10)]);
+        let mut iter = src.iter_mut();
+        assert_eq!(iter.len(), 5);
+        let front = iter.next().unwrap();
+        let back = iter.next_back().unwrap();
+        let front_2 = iter.next().unwrap();
+        let back_2 = iter.next_back().unwrap();
+        let middle = iter.next().unwrap();
+        assert_eq!(iter.len(), 0);
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+        // All of the references are live at once and must be disjoint.
+        *front.0 += 100;
+        *back.0 += 200;
+        *front_2.0 += 300;
+        *back_2.0 += 400;
+        *middle.0 += 500;
+        assert_eq!(src.column::<0>(), &[101, 303, 505, 407, 209]);
+    }
+
+    #[test]
+    fn test_shrink_to() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        src.reserve(1000);
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() >= 1000);
+        src.shrink_to(200);
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() <= 200);
+        src.shrink_to(100);
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() <= 100);
+        src.shrink_to(10);
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() <= 10);
+        src.shrink_to(1);
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() >= 4);
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 3, 5, 7]);
+        assert_eq!(b, &[2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn test_shrink_to_no_op() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        src.reserve(1000);
+        let storage = src.storage;
+        let capacity = src.capacity();
+        src.shrink_to(capacity + 100);
+        assert!(src.storage == storage);
+        assert_eq!(src.capacity(), capacity);
+        src.shrink_to(capacity);
+        assert!(src.storage == storage);
+        assert_eq!(src.capacity(), capacity);
+        src.shrink_to_fit();
+        src.shrink_to_fit();
+        assert_eq!(src.capacity(), 4);
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 3, 5, 7]);
+        assert_eq!(b, &[2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        src.reserve(1000);
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() >= 1000);
+        src.shrink_to_fit();
+        assert_eq!(src.len(), 4);
+        assert!(src.capacity() <= src.len());
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 3, 5, 7]);
+        assert_eq!(b, &[2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn test_truncate() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        {
+            let (a, b) = src.as_slices();
+            assert_eq!(a, &[1, 3, 5, 7]);
+            assert_eq!(b, &[2, 4, 6, 8]);
+        }
+        src.truncate(2);
+        {
+            let (a, b) = src.as_slices();
+            assert_eq!(a, &[1, 3]);
+            assert_eq!(b, &[2, 4]);
+            assert_eq!(src.len(), 2);
+            assert!(src.capacity() >= 4);
+        }
+    }
+
+    #[test]
+    fn test_truncate_drops() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        src.extend(vec![
+            (rc.clone(), rc.clone()),
+            (rc.clone(), rc.clone()),
+            (rc.clone(), rc.clone()),
+            (rc.clone(), rc.clone()),
+        ]);
+        assert_eq!(Rc::strong_count(&rc), 9);
+        src.truncate(1);
+        assert_eq!(Rc::strong_count(&rc), 3);
+    }
+
+    #[test]
+    fn test_truncate_drop_order() {
+        struct Recorder(u32, Rc<RefCell<Vec<u32>>>);
+        impl Drop for Recorder {
+            fn drop(&mut self) {
+                self.1.borrow_mut().push(self.0);
+            }
+        }
+
+        let order = Rc::new(RefCell::new(Vec::new()));
+        let mut src = ParallelVec::new();
+        for i in 0..5 {
+            src.push((Recorder(i, order.clone()), i));
+        }
+        src.truncate(2);
+        assert_eq!(*order.borrow(), vec![2, 3, 4]);
+        core::mem::drop(src);
+        assert_eq!(*order.borrow(), vec![2, 3, 4, 0, 1]);
+        let std_order = Rc::new(RefCell::new(Vec::new()));
+        let mut std_vec: Vec<_> = (0..5).map(|i| Recorder(i, std_order.clone())).collect();
+        std_vec.truncate(2);
+        core::mem::drop(std_vec);
+        assert_eq!(*std_order.borrow(), *order.borrow());
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (2, 4), (3, 6), (4, 8), (5, 10)]);
+        src.retain(|(a, _)| a % 2 == 1);
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 3, 5]);
+        assert_eq!(b, &[2, 6, 10]);
+    }
+
+    #[test]
+    fn test_retain_mut() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (2, 4), (3, 6), (4, 8), (5, 10)]);
+        src.retain_mut(|(a, b)| {
+            *b += 1;
+            *a > 2
+        });
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[3, 4, 5]);
+        assert_eq!(b, &[7, 9, 11]);
+    }
+
+    #[test]
+    fn test_retain_panic_does_not_leak() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        for i in 0..5 {
+            src.push((i, rc.clone()));
+        }
+        assert_eq!(Rc::strong_count(&rc), 6);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            src.retain(|(i, _)| {
+                if *i == 2 {
+                    panic!("predicate panicked");
+                }
+                *i != 0
+            });
+        }));
+        assert!(result.is_err());
+        // Row 0 was removed, row 1 kept, and the unprocessed rows 2..5 are kept.
+        assert_eq!(src.len(), 4);
+        assert_eq!(src.column::<0>(), &[1, 2, 3, 4]);
+        assert_eq!(Rc::strong_count(&rc), 5);
+        core::mem::drop(src);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 1), (1, 1), (1, 2), (2, 2), (2, 2), (1, 1)]);
+        src.dedup();
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 1, 2, 1]);
+        assert_eq!(b, &[1, 2, 2, 1]);
+    }
+
+    #[test]
+    fn test_dedup_by_key() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        for i in [1, 1, 2, 3, 3, 3, 1] {
+            src.push((i, rc.clone()));
+        }
+        src.dedup_by_key(|(a, _)| *a);
+        assert_eq!(src.column::<0>(), &[1, 2, 3, 1]);
+        assert_eq!(Rc::strong_count(&rc), 5);
+    }
+
+    #[test]
+    fn test_dedup_into() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![
+            (1, 'a'),
+            (1, 'b'),
+            (2, 'c'),
+            (3, 'd'),
+            (3, 'e'),
+            (3, 'f'),
+        ]);
+        let mut sink = ParallelVec::new();
+        sink.push((0, 'z'));
+        src.dedup_into(|(a, _), (b, _)| a == b, &mut sink);
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 2, 3]);
+        assert_eq!(b, &['a', 'c', 'd']);
+        let (a, b) = sink.as_slices();
+        assert_eq!(a, &[0, 1, 3, 3]);
+        assert_eq!(b, &['z', 'b', 'e', 'f']);
+    }
+
+    #[test]
+    fn test_copied() {
+        let mut src = ParallelVec::new();
+        src.push((1u32, 1.5f64));
+        src.push((2u32, 2.5f64));
+        src.push((3u32, 3.5f64));
+        let rows: Vec<(u32, f64)> = src.copied().collect();
+        assert_eq!(rows, vec![(1, 1.5), (2, 2.5), (3, 3.5)]);
+        let rows: Vec<(u32, f64)> = src.index(1..).copied().collect();
+        assert_eq!(rows, vec![(2, 2.5), (3, 3.5)]);
+        assert_eq!(src.len(), 3);
+    }
+
+    #[test]
+    fn test_cloned() {
+        let mut src = ParallelVec::new();
+        src.push((String::from("a"), 1u32));
+        src.push((String::from("b"), 2u32));
+        let mut rows: Vec<(String, u32)> = src.cloned().collect();
+        assert_eq!(rows, vec![(String::from("a"), 1), (String::from("b"), 2)]);
+        rows[0].0.push('z');
+        rows[1].1 = 5;
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[String::from("a"), String::from("b")]);
+        assert_eq!(b, &[1, 2]);
+    }
+
+    #[test]
+    fn test_resize() {
+        let mut src = ParallelVec::new();
+        src.resize(3, (1, 'a'));
+        src.resize_with(5, || (2, 'b'));
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 1, 1, 2, 2]);
+        assert_eq!(b, &['a', 'a', 'a', 'b', 'b']);
+        src.resize(2, (0, 'z'));
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 1]);
+        assert_eq!(b, &['a', 'a']);
+    }
+
+    #[test]
+    fn test_resize_shrink_keeps_capacity() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::with_capacity(1000);
+        src.resize_with(500, || (0u64, rc.clone()));
+        let capacity = src.capacity();
+        let storage = src.as_mut_ptrs();
+        assert!(capacity >= 1000);
+        src.resize(10, (1u64, rc.clone()));
+        assert_eq!(src.len(), 10);
+        assert_eq!(src.capacity(), capacity);
+        assert_eq!(src.as_mut_ptrs(), storage);
+        assert_eq!(Rc::strong_count(&rc), 11);
+        src.resize_with(5, || unreachable!());
+        assert_eq!(src.len(), 5);
+        assert_eq!(src.capacity(), capacity);
+        assert_eq!(Rc::strong_count(&rc), 6);
+    }
+
+    #[test]
+    fn test_field_position_of() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1u32, 5u8), (2, 4), (3, 3), (2, 2), (1, 1)]);
+        assert_eq!(src.field_position_of::<0>(2), Some(1));
+        assert_eq!(src.field_position_of::<1>(2), Some(3));
+        assert_eq!(src.field_position_of::<0>(4), None);
+        assert_eq!(src.index(2..).field_position_of::<0>(1), Some(2));
+        let empty: ParallelVec<(u32, u8)> = ParallelVec::new();
+        assert_eq!(empty.field_position_of::<0>(0), None);
+    }
+
+    #[test]
+    fn test_apply_updates() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        for i in 0..5 {
+            src.push((i, rc.clone()));
+        }
+        src.apply_updates(vec![
+            (1, (10, rc.clone())),
+            (4, (40, rc.clone())),
+            (1, (11, rc.clone())),
+        ]);
+        assert_eq!(src.column::<0>(), &[0, 11, 2, 3, 40]);
+        assert_eq!(Rc::strong_count(&rc), 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_apply_updates_out_of_bounds() {
+        let mut src = ParallelVec::new();
+        src.push((0, 0));
+        src.apply_updates(vec![(0, (1, 1)), (1, (2, 2))]);
+    }
+
+    #[test]
+    fn test_iter_last_and_nth_back() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(0, 'a'), (1, 'b'), (2, 'c'), (3, 'd'), (4, 'e')]);
+        assert_eq!(src.iter().last(), Some((&4, &'e')));
+        assert_eq!(src.iter_mut().last(), Some((&mut 4, &mut 'e')));
+        assert_eq!(src.index(0..0).iter().last(), None);
+
+        let mut iter = src.iter();
+        assert_eq!(iter.nth_back(1), Some((&3, &'d')));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some((&0, &'a')));
+        assert_eq!(iter.nth_back(0), Some((&2, &'c')));
+        assert_eq!(iter.nth_back(1), None);
+        assert_eq!(iter.next(), None);
+
+        let mut iter = src.iter_mut();
+        assert_eq!(iter.nth_back(4), Some((&mut 0, &mut 'a')));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_tail_mut() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(0, 'a'), (1, 'b'), (2, 'c'), (3, 'd')]);
+        let mut tail = src.tail_mut(2);
+        assert_eq!(tail.len(), 2);
+        for (a, _) in tail.iter_mut() {
+            *a *= 10;
+        }
+        assert_eq!(src.len(), 4);
+        assert_eq!(src.column::<0>(), &[0, 1, 20, 30]);
+        assert!(src.tail_mut(4).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_tail_mut_out_of_bounds() {
+        let mut src = ParallelVec::new();
+        src.push((0, 'a'));
+        src.tail_mut(2);
+    }
+
+    #[test]
+    fn test_split2_mut() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(0u32, 1u32), (0, 2), (0, 3)]);
+        let (a, b) = src.split2_mut();
+        for (a, b) in a.iter_mut().zip(b) {
+            *a = *b * 2;
+        }
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[2, 4, 6]);
+        assert_eq!(b, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_capacity_mixed_zst() {
+        let mut src: ParallelVec<(ZST, u64)> = ParallelVec::with_capacity(10);
+        assert_eq!(src.capacity(), 10);
+        let (_, ptr) = src.as_mut_ptrs();
+        for i in 0..10 {
+            src.push((ZST, i));
+        }
+        assert_eq!(src.capacity(), 10);
+        assert_eq!(src.as_mut_ptrs().1, ptr);
+        src.push((ZST, 10));
+        assert!(src.capacity() > 10);
+        assert_eq!(src.column::<1>(), &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn test_into_boxed_slices() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::with_capacity(10);
+        src.push((1u64, rc.clone(), ZST));
+        src.push((2u64, rc.clone(), ZST));
+        src.push((3u64, rc.clone(), ZST));
+        let (a, b, c) = src.into_boxed_slices();
+        assert_eq!(&*a, &[1, 2, 3]);
+        assert_eq!(b.len(), 3);
+        assert_eq!(&*c, &[ZST, ZST, ZST]);
+        assert_eq!(Rc::strong_count(&rc), 4);
+        core::mem::drop(b);
+        assert_eq!(Rc::strong_count(&rc), 1);
+
+        let empty: ParallelVec<(u64, u8)> = ParallelVec::with_capacity(10);
+        let (a, b) = empty.into_boxed_slices();
+        assert!(a.is_empty());
+        assert!(b.is_empty());
+    }
+
+    #[test]
+    fn test_slice() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(0, 'a'), (1, 'b'), (2, 'c'), (3, 'd')]);
+        let slice = src.slice(1..3);
+        assert_eq!(slice.as_slices(), (&[1, 2][..], &['b', 'c'][..]));
+        assert_eq!(slice.slice(1..).as_slices(), (&[2][..], &['c'][..]));
+        assert_eq!(src.slice(..=1).len(), 2);
+        assert!(src.slice(4..).is_empty());
+        assert_eq!(src.slice(..).len(), 4);
+    }
+
+    #[test]
+    fn test_slice_mut() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(0, 'a'), (1, 'b'), (2, 'c'), (3, 'd')]);
+        let mut slice = src.slice_mut(1..3);
+        slice.set(0, (10, 'x'));
+        slice.slice_mut(1..).set(0, (20, 'y'));
+        assert!(src.slice_mut(4..).is_empty());
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[0, 10, 20, 3]);
+        assert_eq!(b, &['a', 'x', 'y', 'd']);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_out_of_bounds() {
+        let mut src = ParallelVec::new();
+        src.push((0, 'a'));
+        src.slice(0..2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_mut_inverted_range() {
+        let mut src = ParallelVec::new();
+        src.push((0, 'a'));
+        src.push((1, 'b'));
+        #[allow(clippy::reversed_empty_ranges)]
+        src.slice_mut(2..1);
+    }
+
+    #[test]
+    fn test_sort_panic_keeps_all_rows() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        for i in (0..20u32).rev() {
+            src.push((i, rc.clone()));
+        }
+        let comparisons = core::cell::Cell::new(0);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            src.sort_by(|(a, _), (b, _)| {
+                comparisons.set(comparisons.get() + 1);
+                if comparisons.get() == 10 {
+                    panic!("comparator panicked");
+                }
+                a.cmp(b)
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(src.len(), 20);
+        let mut values: Vec<u32> = src.column::<0>().to_vec();
+        values.sort_unstable();
+        assert_eq!(values, (0..20).collect::<Vec<_>>());
+        assert_eq!(Rc::strong_count(&rc), 21);
+        core::mem::drop(src);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn test_from_iter_exact_size() {
+        let src: ParallelVec<(u32, u64)> = (0..100).map(|i| (i, i as u64 * 2)).collect();
+        assert_eq!(src.len(), 100);
+        // A single exact allocation, as growing would round up the capacity.
+        assert_eq!(src.capacity(), 100);
+        assert_eq!(src.column::<0>(), &(0..100).collect::<Vec<_>>()[..]);
+        assert_eq!(
+            src.column::<1>(),
+            &(0..100).map(|i| i * 2).collect::<Vec<_>>()[..]
+        );
+
+        let src: ParallelVec<(u32, u64)> =
+            (0..100).filter(|i| i % 2 == 0).map(|i| (i, 0)).collect();
+        assert_eq!(src.len(), 50);
+        assert_eq!(
+            src.column::<0>(),
+            &(0..100).step_by(2).collect::<Vec<_>>()[..]
+        );
+    }
+
+    #[test]
+    fn test_truncate_returning() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(0, 'a'), (1, 'b'), (2, 'c'), (3, 'd'), (4, 'e')]);
+        assert_eq!(src.truncate_returning(2), 3);
+        assert_eq!(src.len(), 2);
+        assert_eq!(src.truncate_returning(5), 0);
+        assert_eq!(src.len(), 2);
+    }
+
+    #[test]
+    fn test_index_range_to_inclusive() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(0, 'a'), (1, 'b'), (2, 'c'), (3, 'd')]);
+        assert_eq!(
+            src.index(..=2).as_slices(),
+            (&[0, 1, 2][..], &['a', 'b', 'c'][..])
+        );
+        assert_eq!(src.get(..=3).map(|s| s.len()), Some(4));
+        assert!(src.get(..=4).is_none());
+        let mut slice = src.index_mut(..=1);
+        slice.set(1, (10, 'x'));
+        assert_eq!(src.column::<0>(), &[0, 10, 2, 3]);
+        assert!(src.get_mut(..=4).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_range_to_inclusive_out_of_bounds() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(0, 'a'), (1, 'b'), (2, 'c'), (3, 'd')]);
+        src.index(..=4);
+    }
+
+    #[test]
+    fn test_index_range_to_inclusive_max() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(0, 'a'), (1, 'b')]);
+        assert!(src.get(..=usize::MAX).is_none());
+        assert!(src.get_mut(..=usize::MAX).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn test_index_range_to_inclusive_max_panics() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(0, 'a'), (1, 'b')]);
+        src.index_mut(..=usize::MAX);
+    }
+
+    #[test]
+    fn test_drain_full() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        for i in 0..5 {
+            src.push((i, rc.clone()));
+        }
+        let drained: Vec<(i32, Rc<i32>)> = src.drain(..).collect();
+        assert!(src.is_empty());
+        assert_eq!(
+            drained.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+        core::mem::drop(drained);
+        assert_eq!(Rc::strong_count(&rc), 1);
+        src.push((5, rc.clone()));
+        assert_eq!(src.column::<0>(), &[5]);
+    }
+
+    #[test]
+    fn test_drain_inclusive() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(0, 'a'), (1, 'b'), (2, 'c'), (3, 'd'), (4, 'e')]);
+        let drained: Vec<(i32, char)> = src.drain(1..=2).collect();
+        assert_eq!(drained, vec![(1, 'b'), (2, 'c')]);
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[0, 3, 4]);
+        assert_eq!(b, &['a', 'd', 'e']);
+    }
+
+    #[test]
+    fn test_drain_open_ranges() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(0, 'a'), (1, 'b'), (2, 'c'), (3, 'd'), (4, 'e')]);
+        assert_eq!(src.drain(3..).collect::<Vec<_>>(), vec![(3, 'd'), (4, 'e')]);
+        assert_eq!(src.drain(..1).rev().collect::<Vec<_>>(), vec![(0, 'a')]);
+        assert_eq!(src.column::<0>(), &[1, 2]);
+    }
+
+    #[test]
+    fn test_drain_drop_unconsumed() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        for i in 0..6 {
+            src.push((i, rc.clone()));
+        }
+        let mut drain = src.drain(1..4);
+        assert_eq!(drain.len(), 3);
+        assert_eq!(drain.next().map(|(i, _)| i), Some(1));
+        core::mem::drop(drain);
+        assert_eq!(src.column::<0>(), &[0, 4, 5]);
+        assert_eq!(Rc::strong_count(&rc), 4);
+    }
+
+    #[test]
+    fn test_drain_drop_partially_consumed_both_ends() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        for i in 0..8 {
+            src.push((i, rc.clone()));
+        }
+        let mut drain = src.drain(1..7);
+        assert_eq!(drain.next_back().map(|(i, _)| i), Some(6));
+        assert_eq!(drain.next().map(|(i, _)| i), Some(1));
+        assert_eq!(drain.next_back().map(|(i, _)| i), Some(5));
+        core::mem::drop(drain);
+        assert_eq!(src.column::<0>(), &[0, 7]);
+        assert_eq!(Rc::strong_count(&rc), 3);
+        core::mem::drop(src);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_drain_out_of_bounds() {
+        let mut src = ParallelVec::new();
+        src.push((0, 'a'));
+        src.drain(..=1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "length exceeds capacity")]
+    fn test_len_exceeding_capacity_is_caught() {
+        // The corrupted vec must never be dropped.
+        let mut src = core::mem::ManuallyDrop::new(ParallelVec::<(u32, u64)>::with_capacity(4));
+        src.len = src.capacity + 1;
+        src.push((0, 0));
+    }
+
+    #[test]
+    fn test_rposition_by() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![
+            (1, 'a'),
+            (2, 'b'),
+            (3, 'c'),
+            (4, 'd'),
+            (5, 'e'),
+            (7, 'f'),
+        ]);
+        assert_eq!(src.rposition_by(|(a, _)| a % 2 == 0), Some(3));
+        assert_eq!(src.rposition_by(|(a, _)| *a > 10), None);
+        assert_eq!(src.index(..3).rposition_by(|(a, _)| a % 2 == 0), Some(1));
+    }
+
+    #[test]
+    fn test_field_align_to() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1u32, 'a'), (2, 'b'), (3, 'c')]);
+        let (prefix, bytes, suffix) = unsafe { src.field_align_to::<0, u8>() };
+        assert!(prefix.is_empty());
+        assert!(suffix.is_empty());
+        assert_eq!(bytes.len(), 12);
+        assert_eq!(bytes[..4], 1u32.to_ne_bytes());
+        assert_eq!(bytes[8..], 3u32.to_ne_bytes());
+
+        let (prefix, middle, suffix) = unsafe { src.field_align_to::<0, u64>() };
+        assert_eq!(prefix.len() + middle.len() * 2 + suffix.len(), 3);
+    }
+
+    #[test]
+    fn test_first_push_allocates() {
+        let mut src: ParallelVec<(u8, u64, String)> = ParallelVec::new();
+        assert_eq!(src.capacity(), 0);
+        src.push((1, 2, String::from("a")));
+        assert!(src.capacity() >= 1);
+        let (a, b, c) = src.as_slices();
+        assert_eq!(a, &[1]);
+        assert_eq!(b, &[2]);
+        assert_eq!(c, &[String::from("a")]);
+    }
+
+    #[test]
+    fn test_iter_mut_read() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1.0f32, 0.5f32), (2.0, 1.5), (3.0, -1.0)]);
+        for (position, velocity) in src.iter_mut_read() {
+            *position += *velocity;
+        }
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1.5, 3.5, 2.0]);
+        assert_eq!(b, &[0.5, 1.5, -1.0]);
+    }
+
+    #[test]
+    fn test_entry_by_key() {
+        let mut src: ParallelVec<(u32, &'static str)> = ParallelVec::new();
+        // Insert into an empty vec.
+        src.entry_by_key(5, |(k, _)| *k).or_insert((5, "e"));
+        // Insert at the front.
+        src.entry_by_key(1, |(k, _)| *k).or_insert((1, "a"));
+        // Insert in the middle.
+        let entry = src.entry_by_key(3, |(k, _)| *k);
+        assert!(matches!(entry, Entry::Vacant(_)));
+        assert_eq!(entry.index(), 1);
+        entry.or_insert_with(|| (3, "c"));
+        // Update an existing key.
+        let (_, value) = src.entry_by_key(5, |(k, _)| *k).or_insert((5, "unused"));
+        *value = "E";
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 3, 5]);
+        assert_eq!(b, &["a", "c", "E"]);
+
+        match src.entry_by_key(3, |(k, _)| *k) {
+            Entry::Occupied(mut entry) => {
+                assert_eq!(entry.get(), (&3, &"c"));
+                assert_eq!(entry.insert((3, "C")), (3, "c"));
+                assert_eq!(entry.remove(), (3, "C"));
+            }
+            Entry::Vacant(_) => panic!("expected an occupied entry"),
+        }
+        assert_eq!(src.column::<0>(), &[1, 5]);
+    }
+
+    #[test]
+    fn test_get_unchecked_range() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(0, 'a'), (1, 'b'), (2, 'c'), (3, 'd')]);
+        unsafe {
+            let slice = src.get_unchecked_range(1..3);
+            assert_eq!(slice.as_slices(), (&[1, 2][..], &['b', 'c'][..]));
+            assert!(src.get_unchecked_range(4..4).is_empty());
+            src.get_unchecked_range_mut(2..4).set(1, (30, 'x'));
+        }
+        assert_eq!(src.column::<0>(), &[0, 1, 2, 30]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_get_unchecked_range_debug_asserts() {
+        let mut src = ParallelVec::new();
+        src.push((0, 'a'));
+        unsafe {
+            src.get_unchecked_range(0..2);
+        }
+    }
+
+    #[test]
+    fn test_drop_range_drops_every_field() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        for _ in 0..10 {
+            src.push((rc.clone(), 0u8, rc.clone()));
+        }
+        assert_eq!(Rc::strong_count(&rc), 21);
+        src.truncate(6);
+        assert_eq!(Rc::strong_count(&rc), 13);
+        let mut iter = src.into_iter();
+        iter.next();
+        iter.next_back();
+        assert_eq!(Rc::strong_count(&rc), 9);
+        core::mem::drop(iter);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn test_small_explicit_capacity_growth() {
+        let mut src = ParallelVec::with_capacity(1);
+        src.push((0u64, 0u8));
+        assert_eq!(src.capacity(), 1);
+        src.push((1, 1));
+        assert_eq!(src.capacity(), 2);
+        src.push((2, 2));
+        assert_eq!(src.capacity(), 4);
+
+        let mut src = ParallelVec::new();
+        src.push((0u64, 0u8));
+        assert_eq!(src.capacity(), 4);
+    }
+
+    #[test]
+    fn test_iter_peek() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(0, 'a'), (1, 'b')]);
+        let mut iter = src.iter();
+        assert_eq!(iter.peek(), Some((&0, &'a')));
+        assert_eq!(iter.peek(), Some((&0, &'a')));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some((&0, &'a')));
+        assert_eq!(iter.peek(), iter.clone().next());
+        assert_eq!(iter.next(), Some((&1, &'b')));
+        assert_eq!(iter.peek(), None);
+    }
+
+    #[test]
+    fn test_clone_with_capacity() {
+        let mut src = ParallelVec::with_capacity(1000);
+        src.extend(vec![(0, String::from("a")), (1, String::from("b"))]);
+        src.push((2, String::from("c")));
+        src.push((3, String::from("d")));
+        let clone = src.clone_with_capacity();
+        assert_eq!(clone.capacity(), src.capacity());
+        assert!(clone.capacity() >= 1000);
+        assert_eq!(clone, src);
+        assert_eq!(src.clone().capacity(), 4);
+    }
+
+    #[test]
+    fn test_into_iter_partial_consumption() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        for i in 0..6 {
+            src.push((i, rc.clone()));
+        }
+        let mut iter = src.into_iter();
+        let taken: Vec<_> = iter.by_ref().take(2).map(|(i, _)| i).collect();
+        assert_eq!(taken, vec![0, 1]);
+        assert_eq!(Rc::strong_count(&rc), 5);
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next().map(|(i, _)| i), Some(2));
+        assert_eq!(iter.next_back().map(|(i, _)| i), Some(5));
+        assert_eq!(Rc::strong_count(&rc), 3);
+        core::mem::drop(iter);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn test_into_iter_meets_in_the_middle() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        for i in 0..3 {
+            src.push((i, rc.clone()));
+        }
+        let mut iter = src.into_iter();
+        assert_eq!(iter.next().map(|(i, _)| i), Some(0));
+        assert_eq!(iter.next_back().map(|(i, _)| i), Some(2));
+        assert_eq!(iter.next_back().map(|(i, _)| i), Some(1));
+        assert!(iter.next_back().is_none());
+        assert!(iter.next().is_none());
+        assert_eq!(iter.len(), 0);
+        core::mem::drop(iter);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn test_resize_with_indexed() {
+        let mut src = ParallelVec::new();
+        src.push((100u32, 100u32));
+        src.resize_with_indexed(5, |i| (i as u32, i as u32 * 2));
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[100, 1, 2, 3, 4]);
+        assert_eq!(b, &[100, 2, 4, 6, 8]);
+        src.resize_with_indexed(2, |_| unreachable!());
+        assert_eq!(src.len(), 2);
+    }
+
+    #[test]
+    fn test_swap_remove_with() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(10u32, 'a'), (11, 'b'), (12, 'c'), (13, 'd')]);
+        let (id, row) = src.swap_remove_with(1, |(id, _)| *id);
+        assert_eq!(id, 11);
+        assert_eq!(row, (11, 'b'));
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[10, 13, 12]);
+        assert_eq!(b, &['a', 'd', 'c']);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_swap_remove_with_out_of_bounds() {
+        let mut src = ParallelVec::new();
+        src.push((0, 'a'));
+        src.swap_remove_with(1, |_| ());
+    }
+
+    #[test]
+    fn test_shrink_to_fit_zst() {
+        let mut src = ParallelVec::with_capacity(1000);
+        for _ in 0..3 {
+            src.push((ZST, ZST2));
+        }
+        assert_eq!(src.capacity(), 1000);
+        src.shrink_to_fit();
+        assert_eq!(src.capacity(), 3);
+        assert_eq!(src.len(), 3);
+        src.clear();
+        src.shrink_to_fit();
+        assert_eq!(src.capacity(), 0);
+        src.push((ZST, ZST2));
+        assert_eq!(src.len(), 1);
+    }
+
+    #[test]
+    fn test_into_iter_ref() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 'a'), (2, 'b'), (3, 'c')]);
+        let mut sum = 0;
+        let mut chars = Vec::new();
+        for (a, b) in &src {
+            sum += *a;
+            chars.push(*b);
+        }
+        assert_eq!(sum, 6);
+        assert_eq!(chars, vec!['a', 'b', 'c']);
+    }
+
+    #[test]
+    fn test_into_iter_mut_ref() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 'a'), (2, 'b'), (3, 'c')]);
+        for (a, b) in &mut src {
+            *a *= 10;
+            *b = b.to_ascii_uppercase();
+        }
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[10, 20, 30]);
+        assert_eq!(b, &['A', 'B', 'C']);
+    }
+
+    #[test]
+    fn test_drain_middle_keeps_head() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![
+            (0, 'a'),
+            (1, 'b'),
+            (2, 'c'),
+            (3, 'd'),
+            (4, 'e'),
+            (5, 'f'),
+        ]);
+        let head = src.as_mut_ptrs();
+        let drained: Vec<(i32, char)> = src.drain(2..4).collect();
+        assert_eq!(drained, vec![(2, 'c'), (3, 'd')]);
+        assert_eq!(src.as_mut_ptrs(), head);
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[0, 1, 4, 5]);
+        assert_eq!(b, &['a', 'b', 'e', 'f']);
+    }
+
+    // Swapping an element with itself aliases both pointers. This is checked
+    // under Miri in CI.
+    #[test]
+    fn test_swap_same_index() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        for i in 0..3 {
+            src.push((i, rc.clone(), String::from("a")));
+        }
+        src.swap(1, 1);
+        unsafe { src.swap_unchecked(2, 2) };
+        assert_eq!(src.column::<0>(), &[0, 1, 2]);
+        assert_eq!(src.column::<2>(), &["a", "a", "a"]);
+        assert_eq!(Rc::strong_count(&rc), 4);
+        core::mem::drop(src);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn test_as_slices_shared() {
+        fn first_names(vec: &ParallelVec<(String, u32)>) -> &[String] {
+            vec.as_slices().0
+        }
+        let mut src = ParallelVec::new();
+        src.push((String::from("a"), 1));
+        src.push((String::from("b"), 2));
+        let shared = &src;
+        let (names, values) = shared.as_slices();
+        assert_eq!(names, &["a", "b"]);
+        assert_eq!(values, &[1, 2]);
+        assert_eq!(first_names(shared), &["a", "b"]);
+    }
+
+    #[test]
+    fn test_derive_clone_wrapper() {
+        #[derive(Clone)]
+        struct Wrapper {
+            rows: ParallelVec<(String, u32)>,
+        }
+
+        let mut wrapper = Wrapper {
+            rows: ParallelVec::new(),
+        };
+        wrapper.rows.push((String::from("a"), 1));
+        wrapper.rows.push((String::from("b"), 2));
+        let clone = wrapper.clone();
+        wrapper.rows.column_mut::<0>()[0].push('!');
+        assert_eq!(clone.rows.column::<0>(), &["a", "b"]);
+        assert_eq!(clone.rows.column::<1>(), &[1, 2]);
+        assert_eq!(wrapper.rows.column::<0>(), &["a!", "b"]);
+    }
+
+    #[test]
+    fn test_retain_and_shrink() {
+        let mut plain: ParallelVec<(u32, u64)> = (0..100).map(|i| (i, i as u64)).collect();
+        let mut shrunk = plain.clone();
+        let capacity = plain.capacity();
+        plain.retain(|(a, _)| *a % 10 == 0);
+        shrunk.retain_and_shrink(|(a, _)| *a % 10 == 0);
+        assert_eq!(plain, shrunk);
+        assert_eq!(plain.capacity(), capacity);
+        assert!(shrunk.capacity() < capacity);
+        assert_eq!(shrunk.capacity(), 10);
+
+        // Removing less than three quarters keeps the allocation.
+        let mut kept: ParallelVec<(u32, u64)> = (0..100).map(|i| (i, i as u64)).collect();
+        kept.retain_and_shrink(|(a, _)| *a % 2 == 0);
+        assert_eq!(kept.len(), 50);
+        assert_eq!(kept.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_extend_from_parallel_vec() {
+        let rc = Rc::new(0);
+        let mut dst = ParallelVec::new();
+        dst.push((0, rc.clone()));
+        let mut first = ParallelVec::new();
+        first.push((1, rc.clone()));
+        first.push((2, rc.clone()));
+        let mut second = ParallelVec::new();
+        second.push((3, rc.clone()));
+        dst.extend([first, ParallelVec::new(), second]);
+        assert_eq!(dst.len(), 4);
+        assert_eq!(dst.column::<0>(), &[0, 1, 2, 3]);
+        assert_eq!(Rc::strong_count(&rc), 5);
+        core::mem::drop(dst);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn test_pop_n() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        for i in 0..4 {
+            src.push((i, rc.clone()));
+        }
+        let popped = src.pop_n(2);
+        assert_eq!(popped.column::<0>(), &[2, 3]);
+        assert_eq!(src.column::<0>(), &[0, 1]);
+        assert_eq!(Rc::strong_count(&rc), 5);
+
+        let rest = src.pop_n(10);
+        assert_eq!(rest.column::<0>(), &[0, 1]);
+        assert!(src.is_empty());
+        assert!(src.pop_n(1).is_empty());
+        core::mem::drop((src, popped, rest));
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn test_indexed_iter() {
+        let mut src: ParallelVec<(usize, u8)> = (0..8).map(|i| (i, i as u8)).collect();
+        let indices: Vec<usize> = src.index(2..5).indexed_iter(2).map(|(i, _)| i).collect();
+        assert_eq!(indices, vec![2, 3, 4]);
+        for (idx, (value, _)) in src.index(2..5).indexed_iter(2) {
+            assert_eq!(idx, *value);
+        }
+        for (idx, (value, _)) in src.index_mut(5..8).indexed_iter(5) {
+            assert_eq!(idx, *value);
+        }
+        assert_eq!(src.indexed_iter(0).count(), 8);
+    }
+
+    #[test]
+    fn test_hash_consistent() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash_of(vec: &ParallelVec<(u32, String)>) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            vec.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let mut src = ParallelVec::new();
+        src.push((1, String::from("a")));
+        src.push((2, String::from("b")));
+        let hash = hash_of(&src);
+        assert_eq!(hash_of(&src.clone()), hash);
+        assert_eq!(hash_of(&src.clone_with_capacity()), hash);
+
+        // Neither the capacity nor previously stored rows may affect the hash.
+        for i in 0..32 {
+            src.push((i, String::from("c")));
+        }
+        assert_ne!(hash_of(&src), hash);
+        src.truncate(2);
+        assert_eq!(hash_of(&src), hash);
+    }
+
+    #[test]
+    fn test_grow_capacity() {
+        use super::{grow_capacity, DOUBLING_LIMIT_BYTES};
+
+        // Small allocations keep doubling.
+        assert_eq!(grow_capacity(1024, 1025, 1024), 2048);
+        // Large allocations grow by 1.5x.
+        let rows = DOUBLING_LIMIT_BYTES / 1024 * 2;
+        assert_eq!(grow_capacity(rows, rows + 1, 1024), rows + rows / 2);
+        assert_eq!(grow_capacity(rows, rows * 4, 1024), rows * 4);
+        // Zero-sized rows never allocate, so never stop doubling.
+        assert_eq!(grow_capacity(1 << 40, (1 << 40) + 1, 0), 1 << 41);
+        assert_eq!(grow_capacity(usize::MAX - 1, usize::MAX, 0), usize::MAX);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_reserve_grows_slower_when_large() {
+        let rows = super::DOUBLING_LIMIT_BYTES / <(u64, u64)>::ROW_SIZE;
+        let mut src: ParallelVec<(u64, u64)> = ParallelVec::with_capacity(rows);
+        src.resize(rows, (0, 0));
+        assert_eq!(src.capacity(), rows);
+        src.push((1, 1));
+        assert_eq!(src.capacity(), rows + rows / 2);
+
+        let mut small: ParallelVec<(u64, u64)> = ParallelVec::with_capacity(16);
+        small.resize(16, (0, 0));
+        small.push((1, 1));
+        assert_eq!(small.capacity(), 32);
+
+        // Padding in the tuple is not allocated, so it does not count towards
+        // the limit: 9 bytes per row are allocated here, not 16.
+        assert_eq!(<(u64, u8)>::ROW_SIZE, 9);
+        let rows = super::DOUBLING_LIMIT_BYTES / core::mem::size_of::<(u64, u8)>();
+        let mut padded: ParallelVec<(u64, u8)> = ParallelVec::with_capacity(rows);
+        padded.resize(rows, (0, 0));
+        padded.push((1, 1));
+        assert_eq!(padded.capacity(), rows * 2);
+    }
+
+    #[test]
+    fn test_into_iter_zst() {
+        let mut src: ParallelVec<(ZST, ZST2)> = ParallelVec::new();
+        for _ in 0..5 {
+            src.push((ZST, ZST2));
+        }
+        let mut iter = src.into_iter();
+        assert_eq!(iter.len(), 5);
+        for _ in 0..4 {
+            assert_eq!(iter.next(), Some((ZST, ZST2)));
+        }
+        assert_eq!(iter.next_back(), Some((ZST, ZST2)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_index_many_mut() {
+        let mut src: ParallelVec<(u32, String)> = (0..6).map(|i| (i, String::from("a"))).collect();
+        for (value, name) in src.index_many_mut([0, 2, 4]) {
+            *value += 10;
+            name.push('!');
+        }
+        assert_eq!(src.column::<0>(), &[10, 1, 12, 3, 14, 5]);
+        assert_eq!(src.column::<1>(), &["a!", "a", "a!", "a", "a!", "a"]);
+
+        assert!(src.get_many_mut([1, 3, 5]).is_some());
+        assert!(src.get_many_mut([1, 3, 1]).is_none());
+        assert!(src.get_many_mut([1, 6]).is_none());
+        assert_eq!(src.get_many_mut([]).map(|rows: [_; 0]| rows.len()), Some(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_many_mut_duplicate() {
+        let mut src: ParallelVec<(u32, u32)> = (0..4).map(|i| (i, i)).collect();
+        src.index_many_mut([0, 2, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_many_mut_out_of_bounds() {
+        let mut src: ParallelVec<(u32, u32)> = (0..4).map(|i| (i, i)).collect();
+        src.index_many_mut([0, 4]);
+    }
+
+    #[test]
+    fn test_truncate_with() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        for i in 0..5 {
+            src.push((i, rc.clone()));
+        }
+        let mut removed = Vec::new();
+        src.truncate_with(2, |row| removed.push(row));
+        assert_eq!(src.column::<0>(), &[0, 1]);
+        assert_eq!(
+            removed.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+            vec![2, 3, 4]
+        );
+        assert_eq!(Rc::strong_count(&rc), 6);
+        src.truncate_with(4, |_| panic!("nothing should be removed"));
+        assert_eq!(src.len(), 2);
+        core::mem::drop(removed);
+        assert_eq!(Rc::strong_count(&rc), 3);
+    }
+
+    #[test]
+    fn test_ord() {
+        use core::cmp::Ordering;
+
+        let short = ParallelVec::from(vec![(1, 2)]);
+        let long = ParallelVec::from(vec![(1, 2), (0, 0)]);
+        assert!(short < long);
+        assert_eq!(short.cmp(&long), Ordering::Less);
+        assert_eq!(long.cmp(&short), Ordering::Greater);
+        assert_eq!(short.cmp(&short.clone()), Ordering::Equal);
+        assert!(ParallelVec::<(i32, i32)>::new() < short);
+
+        // The first differing row decides, regardless of length.
+        let bigger = ParallelVec::from(vec![(2, 0)]);
+        assert!(long < bigger);
+        let second_field = ParallelVec::from(vec![(1, 3)]);
+        assert!(long < second_field);
+
+        assert_eq!(
+            ParallelVec::from(vec![(1.0, 0)]).partial_cmp(&ParallelVec::from(vec![(f64::NAN, 0)])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_from_array() {
+        let src = ParallelVec::from([(1, 2), (3, 4), (5, 6)]);
+        assert_eq!(src.capacity(), 3);
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 3, 5]);
+        assert_eq!(b, &[2, 4, 6]);
+
+        let empty: ParallelVec<(u8, String)> = ParallelVec::from([]);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_collect_columns() {
+        let src = ParallelVec::from([(1, 1.5), (2, 2.5), (3, 3.5)]);
+        let borrowed = &src;
+        let (ints, floats): (Vec<i32>, Vec<f64>) = borrowed.collect_columns();
+        assert_eq!(ints, vec![1, 2, 3]);
+        assert_eq!(floats, vec![1.5, 2.5, 3.5]);
+        assert_eq!(src.len(), 3);
+    }
 
-impl<Param: ParallelParam> Default for ParallelVec<Param> {
-    fn default() -> Self {
-        Self::new()
+    #[test]
+    #[should_panic(expected = "capacity overflow")]
+    fn test_repeat_overflow() {
+        let mut src: ParallelVec<(ZST, ZST2)> = ParallelVec::new();
+        src.push((ZST, ZST2));
+        src.push((ZST, ZST2));
+        src.repeat(usize::MAX / 2 + 1);
     }
-}
 
-impl<Param: ParallelParam> Deref for ParallelVec<Param> {
-    type Target = ParallelSliceMut<'static, Param>;
-    fn deref(&self) -> &Self::Target {
-        // SAFE: Both ParallelVec and ParallelSliceMut have the same
-        // layout in memory due to #[repr(C)]
-        unsafe {
-            let ptr: *const Self = self;
-            &*(ptr.cast::<Self::Target>())
+    // Both halves point into the same allocations. This is checked under Miri
+    // in CI.
+    #[test]
+    fn test_split_at_mut() {
+        let mut src: ParallelVec<(u32, String)> = (0..6).map(|i| (i, String::from("a"))).collect();
+        let (mut left, mut right) = src.split_at_mut(3);
+        assert_eq!(left.len(), 3);
+        assert_eq!(right.len(), 3);
+        for ((a, left_name), (b, right_name)) in left.iter_mut().zip(right.iter_mut()) {
+            core::mem::swap(a, b);
+            left_name.push('l');
+            right_name.push('r');
         }
+        let (a, b) = left.index_mut(0);
+        *a += 100;
+        b.push('!');
+        right.index_mut(2).1.push('?');
+        assert_eq!(src.column::<0>(), &[103, 4, 5, 0, 1, 2]);
+        assert_eq!(src.column::<1>(), &["al!", "al", "al", "ar", "ar", "ar?"]);
+
+        let (left, right) = src.split_at(0);
+        assert!(left.is_empty());
+        assert_eq!(right.len(), 6);
+        let (left, right) = src.split_at_mut(6);
+        assert_eq!(left.len(), 6);
+        assert!(right.is_empty());
     }
-}
 
-impl<Param: ParallelParam> DerefMut for ParallelVec<Param> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        // SAFE: Both ParallelVec and ParallelSliceMut have the same
-        // layout in memory due to #[repr(C)]
-        unsafe {
-            let ptr: *mut Self = self;
-            &mut *(ptr.cast::<Self::Target>())
-        }
+    #[test]
+    #[should_panic]
+    fn test_split_at_mut_out_of_bounds() {
+        let mut src: ParallelVec<(u32, u32)> = (0..4).map(|i| (i, i)).collect();
+        src.split_at_mut(5);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::ParallelVec;
-    use std::convert::From;
-    use std::rc::Rc;
-    use std::vec::Vec;
+    #[test]
+    fn test_for_each_pair_mut() {
+        let mut src: ParallelVec<(i32, i32)> = (1..=4).map(|i| (i, 0)).collect();
+        let mut pairs = 0;
+        src.for_each_pair_mut(|(value, total), (other, _)| {
+            assert_ne!(value, other);
+            *total += *other;
+            pairs += 1;
+        });
+        assert_eq!(pairs, 12);
+        // Every row accumulates the sum of every other row.
+        assert_eq!(src.column::<1>(), &[9, 8, 7, 6]);
+    }
 
-    #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
-    struct ZST;
+    #[test]
+    fn test_try_reserve() {
+        let mut src: ParallelVec<(u64, String)> = ParallelVec::new();
+        src.push((1, String::from("a")));
+        assert_eq!(src.try_reserve(10), Ok(()));
+        assert!(src.capacity() >= 11);
+        assert_eq!(src.column::<1>(), &["a"]);
 
-    #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
-    struct ZST2;
+        let capacity = src.capacity();
+        assert_eq!(
+            src.try_reserve(usize::MAX),
+            Err(crate::TryReserveError::CapacityOverflow)
+        );
+        assert_eq!(
+            src.try_reserve(usize::MAX / 16),
+            Err(crate::TryReserveError::CapacityOverflow)
+        );
+        assert_eq!(src.capacity(), capacity);
+        src.push((2, String::from("b")));
+        assert_eq!(src.column::<0>(), &[1, 2]);
+    }
 
     #[test]
-    fn layouts_do_not_overlap() {
-        // Trying with both (small, large) and (large, small) to ensure nothing bleeds into anything else.
-        // This verifies we correctly chunk the slices from the larger allocations.
-        let mut vec_ab = ParallelVec::new();
-        let mut vec_ba = ParallelVec::new();
+    #[cfg_attr(miri, ignore)]
+    fn test_try_reserve_alloc_failure() {
+        // The first field is small enough to allocate, but the second is not, so
+        // the vector must be left as is. tests/try_reserve_alloc_failure.rs checks
+        // that the first field's allocation is freed.
+        let mut src: ParallelVec<(u8, [u8; 1 << 40])> = ParallelVec::new();
+        let result = src.try_reserve(1 << 22);
+        assert!(matches!(
+            result,
+            Err(crate::TryReserveError::AllocError { .. })
+        ));
+        assert_eq!(src.capacity(), 0);
+        assert!(src.is_empty());
+        assert_eq!(src.try_reserve(0), Ok(()));
+    }
 
-        fn ab(v: usize) -> (u8, f64) {
-            (v as u8, 200.0 + ((v as f64) / 200.0))
+    #[test]
+    fn test_field_count() {
+        assert_eq!(ParallelVec::<(u8, u16, u32)>::field_count(), 3);
+        assert_eq!(ParallelVec::<(u8, ZST)>::field_count(), 2);
+        assert_eq!(
+            <(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8) as ParallelParam>::FIELD_COUNT,
+            12
+        );
+    }
+
+    #[test]
+    fn test_field_lens_match_len() {
+        fn check(vec: &ParallelVec<(u32, String, ZST)>) {
+            vec.assert_field_lens();
+            let (a, b, c) = vec.as_slices();
+            assert_eq!(a.len(), vec.len());
+            assert_eq!(b.len(), vec.len());
+            assert_eq!(c.len(), vec.len());
         }
 
-        fn ba(v: usize) -> (f64, u8) {
-            (15.0 + ((v as f64) / 16.0), (200 - v) as u8)
+        let mut src = ParallelVec::new();
+        check(&src);
+        for i in 0..5 {
+            src.push((i, String::from("a"), ZST));
+            check(&src);
+        }
+        src.insert(2, (10, String::from("b"), ZST));
+        check(&src);
+        src.remove(0);
+        check(&src);
+        src.swap(0, 3);
+        check(&src);
+        src.swap_remove(1);
+        check(&src);
+        src.truncate(1);
+        check(&src);
+        src.clear();
+        check(&src);
+    }
+
+    #[test]
+    fn test_into_iter_into_parallel_vec() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        for i in 0..5 {
+            src.push((i, rc.clone()));
         }
+        let mut iter = src.into_iter();
+        assert_eq!(iter.next().map(|(i, _)| i), Some(0));
+        assert_eq!(iter.next().map(|(i, _)| i), Some(1));
+        let rest = iter.into_parallel_vec();
+        assert_eq!(rest.column::<0>(), &[2, 3, 4]);
+        assert_eq!(rest.capacity(), 3);
+        assert_eq!(Rc::strong_count(&rc), 4);
 
-        // Combined with the tests inside, also verifies that we are copying the data on grow correctly.
-        for i in 0..100 {
-            vec_ab.push(ab(i));
-            let (a, b) = vec_ab.as_slices();
-            assert_eq!(i + 1, a.len());
-            assert_eq!(i + 1, b.len());
-            assert_eq!(ab(0).0, a[0]);
-            assert_eq!(ab(0).1, b[0]);
-            assert_eq!(ab(i).0, a[i]);
-            assert_eq!(ab(i).1, b[i]);
+        // Only consuming from the back reuses the storage.
+        let mut iter = rest.into_iter();
+        assert_eq!(iter.next_back().map(|(i, _)| i), Some(4));
+        let rest = iter.into_parallel_vec();
+        assert_eq!(rest.column::<0>(), &[2, 3]);
+        assert_eq!(rest.capacity(), 2);
+        core::mem::drop(rest);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
 
-            vec_ba.push(ba(i));
-            let (b, a) = vec_ba.as_slices();
-            assert_eq!(i + 1, a.len());
-            assert_eq!(i + 1, b.len());
-            assert_eq!(ba(0).0, b[0]);
-            assert_eq!(ba(0).1, a[0]);
-            assert_eq!(ba(i).0, b[i]);
-            assert_eq!(ba(i).1, a[i]);
+    #[test]
+    fn test_reserve_exact() {
+        let mut src = ParallelVec::new();
+        src.reserve_exact(1);
+        src.push((1u32, String::from("a")));
+        assert_eq!(src.capacity(), 1);
+        src.reserve_exact(1);
+        src.push((2, String::from("b")));
+        assert_eq!(src.capacity(), 2);
+        src.reserve_exact(3);
+        assert_eq!(src.capacity(), 5);
+        src.reserve_exact(1);
+        assert_eq!(src.capacity(), 5);
+        assert_eq!(src.column::<0>(), &[1, 2]);
+
+        let mut default = ParallelVec::new();
+        default.push((1u32, String::from("a")));
+        assert_eq!(default.capacity(), 4);
+    }
+
+    #[test]
+    fn test_size_of() {
+        // A length, a capacity, and one pointer per field.
+        assert_eq!(
+            core::mem::size_of::<ParallelVec<(u32, u64)>>(),
+            4 * core::mem::size_of::<usize>()
+        );
+    }
+
+    #[test]
+    fn test_swap_remove_many() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        for i in 0..5 {
+            src.push((i, rc.clone()));
         }
+        let removed = src.swap_remove_many(&mut [1, 3]);
+        assert_eq!(removed.column::<0>(), &[3, 1]);
+        assert_eq!(src.column::<0>(), &[0, 4, 2]);
+
+        let removed_again = src.swap_remove_many(&mut [0, 2, 0]);
+        assert_eq!(removed_again.column::<0>(), &[2, 0]);
+        assert_eq!(src.column::<0>(), &[4]);
+        assert!(src.swap_remove_many(&mut []).is_empty());
+        core::mem::drop((src, removed, removed_again));
+        assert_eq!(Rc::strong_count(&rc), 1);
     }
 
     #[test]
-    fn test_new() {
-        let src: ParallelVec<(i32, i32, u64)> = ParallelVec::new();
-        assert_eq!(src.len(), 0);
-        assert_eq!(src.capacity(), 0);
-        assert!(src.is_empty());
+    #[should_panic]
+    fn test_swap_remove_many_out_of_bounds() {
+        let mut src: ParallelVec<(u32, u32)> = (0..4).map(|i| (i, i)).collect();
+        src.swap_remove_many(&mut [1, 4]);
     }
 
     #[test]
-    fn test_default() {
-        let src: ParallelVec<(i32, i32, u64)> = Default::default();
-        assert_eq!(src.len(), 0);
-        assert_eq!(src.capacity(), 0);
-        assert!(src.is_empty());
+    fn test_pop_n_and_swap_remove_many_without_clone() {
+        struct NoClone(u32);
+        let mut src: ParallelVec<(u32, NoClone)> = (0..5).map(|i| (i, NoClone(i))).collect();
+        let popped = src.pop_n(2);
+        assert_eq!(popped.column::<0>(), &[3, 4]);
+        let removed = src.swap_remove_many(&mut [0]);
+        assert_eq!(removed.column::<1>()[0].0, 0);
+        assert_eq!(src.column::<0>(), &[2, 1]);
+        assert!(src.iter().all(|(i, value)| *i == value.0));
     }
 
     #[test]
-    fn test_with_capacity() {
-        let src: ParallelVec<(i32, i32, u64)> = ParallelVec::with_capacity(1000);
-        assert_eq!(src.len(), 0);
-        assert!(src.capacity() >= 1000);
-        assert!(src.is_empty());
+    fn test_array_chunks() {
+        let src: ParallelVec<(u32, u64)> = (0..5).map(|i| (i, i as u64 * 10)).collect();
+        let chunks: Vec<[(&u32, &u64); 2]> = src.array_chunks::<2>().collect();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0], [(&0, &0), (&1, &10)]);
+        assert_eq!(chunks[1], [(&2, &20), (&3, &30)]);
+        assert_eq!(src.index(1..5).array_chunks::<4>().count(), 1);
+        assert_eq!(src.array_chunks::<6>().count(), 0);
     }
 
     #[test]
-    fn test_reserve() {
-        let mut src = ParallelVec::new();
-        src.push((0, 0, 0, 0));
-        assert_eq!(src.len(), 1);
-        assert!(src.capacity() >= 1);
-        src.reserve(10);
-        assert_eq!(src.len(), 1);
-        assert!(src.capacity() >= 10);
-        src.reserve(100);
-        assert_eq!(src.len(), 1);
-        assert!(src.capacity() >= 100);
-        src.reserve(1000);
-        assert_eq!(src.len(), 1);
-        assert!(src.capacity() >= 1000);
-        src.reserve(100000);
-        assert_eq!(src.len(), 1);
-        assert!(src.capacity() >= 10000);
+    #[should_panic]
+    fn test_array_chunks_zero() {
+        let src: ParallelVec<(u32, u64)> = (0..5).map(|i| (i, i as u64)).collect();
+        src.array_chunks::<0>().count();
     }
 
     #[test]
-    fn test_clone() {
-        let mut src = ParallelVec::new();
-        src.push((1.0, 2.0));
-        src.push((3.0, 4.0));
+    fn test_from_iter_panic_does_not_leak() {
+        let rc = Rc::new(0);
+        // An exact size hint takes the unchecked write path.
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            (0..4)
+                .map(|i| {
+                    if i == 2 {
+                        panic!("iterator panicked");
+                    }
+                    (i, rc.clone())
+                })
+                .collect::<ParallelVec<_>>()
+        }));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&rc), 1);
 
-        let dst = src.clone();
-        assert_eq!(dst.len(), 2);
-        assert_eq!(dst.index(0), (&1.0, &2.0));
-        assert_eq!(dst.index(1), (&3.0, &4.0));
+        // No size hint takes the push path.
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            (0..4)
+                .filter(|_| true)
+                .map(|i| {
+                    if i == 2 {
+                        panic!("iterator panicked");
+                    }
+                    (i, rc.clone())
+                })
+                .collect::<ParallelVec<_>>()
+        }));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&rc), 1);
     }
 
     #[test]
-    fn test_works_with_zsts() {
+    fn test_extend_panic_keeps_pushed_rows() {
+        let rc = Rc::new(0);
         let mut src = ParallelVec::new();
-        src.push((1, ZST, 20u64, ZST2));
-        src.push((1, ZST, 21u64, ZST2));
-        src.push((1, ZST, 22u64, ZST2));
-        src.push((1, ZST, 23u64, ZST2));
-        assert_eq!(src.index(0), (&1, &ZST, &20u64, &ZST2));
-        assert_eq!(src.index(1), (&1, &ZST, &21u64, &ZST2));
-        assert_eq!(src.index(2), (&1, &ZST, &22u64, &ZST2));
-        assert_eq!(src.index(3), (&1, &ZST, &23u64, &ZST2));
-        assert_eq!(src.len(), 4);
+        src.push((0, rc.clone()));
+        src.push((1, rc.clone()));
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            src.extend((2..5).map(|i| {
+                if i == 3 {
+                    panic!("iterator panicked");
+                }
+                (i, rc.clone())
+            }));
+        }));
+        assert!(result.is_err());
+        assert_eq!(src.len(), 3);
+        assert_eq!(src.column::<0>(), &[0, 1, 2]);
+        assert_eq!(Rc::strong_count(&rc), 4);
+        src.push((5, rc.clone()));
+        assert_eq!(src.column::<0>(), &[0, 1, 2, 5]);
+        core::mem::drop(src);
+        assert_eq!(Rc::strong_count(&rc), 1);
     }
 
     #[test]
-    fn test_push() {
+    fn test_swap_rows() {
+        let mut src: ParallelVec<(u32, String, ZST)> =
+            (0..3).map(|i| (i, String::from("a"), ZST)).collect();
+        src.column_mut::<1>()[2].push('!');
+        src.swap_rows(0, 2);
+        assert_eq!(src.column::<0>(), &[2, 1, 0]);
+        assert_eq!(src.column::<1>(), &["a!", "a", "a"]);
+        src.swap_rows(1, 1);
+        assert_eq!(src.column::<0>(), &[2, 1, 0]);
+    }
+
+    #[test]
+    fn test_get_borrows_vec() {
+        fn first_name(vec: &ParallelVec<(u32, String)>) -> Option<&str> {
+            vec.get(0).map(|(_, name)| name.as_str())
+        }
+        fn first_id(vec: &mut ParallelVec<(u32, String)>) -> Option<&mut u32> {
+            vec.get_mut(0).map(|(id, _)| id)
+        }
+
         let mut src = ParallelVec::new();
-        src.push((1, 2));
-        assert_eq!(src.index(0), (&1, &2));
-        assert_eq!(src.len(), 1);
+        src.push((1, String::from("a")));
+        src.push((2, String::from("b")));
+        assert_eq!(first_name(&src), Some("a"));
+        *first_id(&mut src).unwrap() = 10;
+        assert_eq!(src.get(0), Some((&10, &String::from("a"))));
+        assert_eq!(src.get(1..).map(|rest| rest.len()), Some(1));
+        assert_eq!(src.get(2), None);
+        assert!(src.get_mut(2).is_none());
     }
 
     #[test]
-    fn test_pop() {
+    fn test_from_aos() {
+        let rc = Rc::new(0);
+        let rows: Vec<_> = (0..5).map(|i| (i, rc.clone())).collect();
+        let src = ParallelVec::from_aos(rows);
+        assert_eq!(src.capacity(), 5);
+        assert_eq!(src.column::<0>(), &[0, 1, 2, 3, 4]);
+        assert_eq!(Rc::strong_count(&rc), 6);
+        core::mem::drop(src);
+        assert_eq!(Rc::strong_count(&rc), 1);
+
+        let empty: ParallelVec<(u8, u16)> = ParallelVec::from(Vec::new());
+        assert!(empty.is_empty());
+        assert_eq!(empty.capacity(), 0);
+    }
+
+    #[test]
+    fn test_clone_empty_does_not_allocate() {
+        let src: ParallelVec<(u32, String)> = ParallelVec::new();
+        let clone = src.clone();
+        assert_eq!(clone.capacity(), 0);
+        assert!(clone.storage == <(u32, String) as ParallelParam>::dangling());
+
+        // Emptied vectors keep their capacity, but their clones do not.
+        let mut src: ParallelVec<(u32, String)> = ParallelVec::with_capacity(16);
+        src.push((1, String::from("a")));
+        src.clear();
+        let clone = src.clone();
+        assert_eq!(clone.capacity(), 0);
+        assert!(clone.storage == <(u32, String) as ParallelParam>::dangling());
+    }
+
+    #[test]
+    fn test_for_each_mut() {
+        let mut src: ParallelVec<(u32, String)> = (0..4).map(|i| (i, String::from("a"))).collect();
+        src.for_each_mut(|(id, name)| {
+            *id *= 2;
+            name.push('!');
+        });
+        assert_eq!(src.column::<0>(), &[0, 2, 4, 6]);
+        assert_eq!(src.column::<1>(), &["a!", "a!", "a!", "a!"]);
+        src.index_mut(1..3).for_each_mut(|(id, _)| *id += 1);
+        assert_eq!(src.column::<0>(), &[0, 3, 5, 6]);
+    }
+
+    #[test]
+    fn test_append_n_with() {
+        let mut vec = ParallelVec::from(vec![(0usize, String::from("0"))]);
+        vec.append_n_with(100, |idx| (idx, format!("{}", idx)));
+        assert_eq!(vec.len(), 101);
+        vec.assert_field_lens();
+        for (idx, (num, name)) in vec.iter().enumerate() {
+            assert_eq!(*num, idx);
+            assert_eq!(*name, format!("{}", idx));
+        }
+
+        // Rows get the same indices as with `resize_with_indexed`.
+        let mut resized = vec.clone();
+        resized.resize_with_indexed(110, |idx| (idx, format!("{}", idx)));
+        vec.append_n_with(9, |idx| (idx, format!("{}", idx)));
+        assert_eq!(vec, resized);
+    }
+
+    #[test]
+    fn test_remove_last() {
+        let mut vec = ParallelVec::new();
+        for idx in 0..8 {
+            vec.push((idx, Rc::new(idx as u64)));
+        }
+        let mut popped = vec.clone();
+        let (ids, _) = vec.as_slices();
+        let ptr = ids.as_ptr();
+        let capacity = vec.capacity();
+
+        assert_eq!(vec.remove(vec.len() - 1), popped.pop());
+        assert_eq!(vec.len(), 7);
+        assert_eq!(vec, popped);
+        // The remaining rows stay in place.
+        let (ids, values) = vec.as_slices();
+        assert_eq!(ids.as_ptr(), ptr);
+        assert_eq!(vec.capacity(), capacity);
+        assert_eq!(ids, &[0, 1, 2, 3, 4, 5, 6]);
+        assert!(values.iter().all(|value| Rc::strong_count(value) == 2));
+
+        assert_eq!(vec.remove(vec.len()), None);
+        assert_eq!(vec.len(), 7);
+    }
+
+    #[test]
+    fn test_insert_n() {
+        let mut vec = ParallelVec::from(vec![
+            (0, String::from("a")),
+            (1, String::from("b")),
+            (2, String::from("c")),
+            (3, String::from("d")),
+        ]);
+        vec.insert_n(1, (9, String::from("x")), 3);
+        assert_eq!(vec.len(), 7);
+        vec.assert_field_lens();
+        let (ids, names) = vec.as_slices();
+        assert_eq!(ids, &[0, 9, 9, 9, 1, 2, 3]);
+        assert_eq!(names, &["a", "x", "x", "x", "b", "c", "d"]);
+
+        vec.insert_n(7, (4, String::from("e")), 1);
+        vec.insert_n(0, (5, String::from("f")), 0);
+        assert_eq!(vec.len(), 8);
+        assert_eq!(vec.index(7), (&4, &String::from("e")));
+    }
+
+    #[test]
+    fn test_insert_n_panic_closes_gap() {
+        #[derive(Debug)]
+        struct CloneBudget(Rc<RefCell<usize>>);
+
+        impl Clone for CloneBudget {
+            fn clone(&self) -> Self {
+                let mut budget = self.0.borrow_mut();
+                if *budget == 0 {
+                    panic!("clone panicked");
+                }
+                *budget -= 1;
+                Self(self.0.clone())
+            }
+        }
+
+        let budget = Rc::new(RefCell::new(0));
         let mut src = ParallelVec::new();
-        src.push((1, 2));
-        src.push((3, 4));
-        src.push((5, 6));
-        src.push((7, 8));
-        assert_eq!(src.len(), 4);
-        let value = src.pop();
-        assert_eq!(value, Some((7, 8)));
-        assert_eq!(src.len(), 3);
-        let value = src.pop();
-        assert_eq!(value, Some((5, 6)));
-        assert_eq!(src.len(), 2);
-        let value = src.pop();
-        assert_eq!(value, Some((3, 4)));
-        assert_eq!(src.len(), 1);
-        let value = src.pop();
-        assert_eq!(value, Some((1, 2)));
-        assert_eq!(src.len(), 0);
-        let value = src.pop();
-        assert_eq!(value, None);
-        assert_eq!(src.len(), 0);
-        let value = src.pop();
-        assert_eq!(value, None);
-        assert_eq!(src.len(), 0);
+        for i in 0..3 {
+            src.push((i, CloneBudget(budget.clone())));
+        }
+        *budget.borrow_mut() = 2;
+        let value = (9, CloneBudget(budget.clone()));
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            src.insert_n(1, value, 5);
+        }));
+        assert!(result.is_err());
+        // The two successful clones are kept, and the tail is moved back after them.
+        assert_eq!(src.len(), 5);
+        assert_eq!(src.column::<0>(), &[0, 9, 9, 1, 2]);
+        assert_eq!(Rc::strong_count(&budget), 6);
+        core::mem::drop(src);
+        assert_eq!(Rc::strong_count(&budget), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_insert_n_out_of_bounds() {
+        let mut vec = ParallelVec::from(vec![(0, 1), (2, 3)]);
+        vec.insert_n(3, (4, 5), 2);
+    }
+
+    #[test]
+    fn test_append_empty() {
+        let mut dst = ParallelVec::from(vec![(0, String::from("a")), (1, String::from("b"))]);
+        let capacity = dst.capacity();
+        let mut empty = ParallelVec::new();
+        dst.append(&mut empty);
+        assert_eq!(dst.len(), 2);
+        assert_eq!(dst.capacity(), capacity);
+        assert!(empty.is_empty());
+
+        let mut empty_with_capacity = ParallelVec::with_capacity(8);
+        dst.append(&mut empty_with_capacity);
+        assert_eq!(dst.len(), 2);
+        assert_eq!(empty_with_capacity.capacity(), 8);
+
+        let mut dst2 = ParallelVec::new();
+        dst2.append(&mut dst);
+        assert_eq!(dst2.len(), 2);
+        dst2.assert_field_lens();
+        assert!(dst.is_empty());
+        assert_eq!(dst.capacity(), capacity);
+        let (ids, names) = dst2.as_slices();
+        assert_eq!(ids, &[0, 1]);
+        assert_eq!(names, &["a", "b"]);
+
+        let mut empty_dst: ParallelVec<(u32, String)> = ParallelVec::new();
+        empty_dst.append(&mut ParallelVec::new());
+        assert!(empty_dst.is_empty());
+        assert_eq!(empty_dst.capacity(), 0);
+    }
+
+    #[test]
+    fn test_custom_allocator() {
+        use crate::{Allocator, Global};
+        use core::alloc::Layout;
+        use core::ptr::NonNull;
+        use std::cell::Cell;
+
+        #[derive(Clone, Default)]
+        struct Counting {
+            live: Rc<Cell<isize>>,
+            allocs: Rc<Cell<usize>>,
+        }
+
+        unsafe impl Allocator for Counting {
+            fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+                self.live.set(self.live.get() + 1);
+                self.allocs.set(self.allocs.get() + 1);
+                Global.allocate(layout)
+            }
+
+            unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+                self.live.set(self.live.get() - 1);
+                Global.deallocate(ptr, layout)
+            }
+        }
+
+        let allocator = Counting::default();
+        let mut src = ParallelVec::new_in(allocator.clone());
+        for i in 0..10 {
+            src.push((i, String::from("a"), ZST));
+        }
+        // Only the two non-zero-sized columns are allocated.
+        assert_eq!(allocator.live.get(), 2);
+        assert!(allocator.allocs.get() > 2);
+
+        let mut other = ParallelVec::with_capacity_in(4, allocator.clone());
+        other.push((10, String::from("b"), ZST));
+        src.append(&mut other);
+        assert_eq!(src.len(), 11);
+        assert!(other.is_empty());
+        assert_eq!(allocator.live.get(), 4);
+
+        let clone = src.clone();
+        assert_eq!(clone, src);
+        src.shrink_to_fit();
+        assert_eq!(src.try_reserve(5), Ok(()));
+        let popped = src.pop_n(3);
+        assert_eq!(popped.column::<0>(), &[8, 9, 10]);
+        src.retain(|(i, _, _)| i % 2 == 0);
+        assert_eq!(src.column::<0>(), &[0, 2, 4, 6]);
+
+        core::mem::drop((src, other, clone, popped));
+        assert_eq!(allocator.live.get(), 0);
     }
 
     #[test]
-    fn test_insert() {
+    fn test_reverse() {
         let mut src = ParallelVec::new();
-        src.insert(0, (1, 2));
-        src.insert(0, (3, 4));
-        src.insert(1, (4, 5));
-        assert_eq!(src.index(0), (&3, &4));
-        assert_eq!(src.index(1), (&4, &5));
-        assert_eq!(src.index(2), (&1, &2));
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        {
+            let (a, b) = src.as_slices();
+            assert_eq!(a, &[1, 3, 5, 7]);
+            assert_eq!(b, &[2, 4, 6, 8]);
+        }
+        src.reverse();
+        {
+            let (a, b) = src.as_slices();
+            assert_eq!(a, &[7, 5, 3, 1]);
+            assert_eq!(b, &[8, 6, 4, 2]);
+        }
     }
 
     #[test]
-    #[should_panic]
-    fn test_insert_panics() {
+    fn test_sort_by() {
         let mut src = ParallelVec::new();
-        src.insert(0, (1, 2));
-        src.insert(0, (3, 4));
-        src.insert(1, (4, 5));
-        src.insert(20, (4, 5));
+        src.extend(vec![(2, 0), (3, 1), (1, 2), (5, 3), (4, 4)]);
+        src.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 2, 3, 4, 5]);
+        assert_eq!(b, &[2, 0, 1, 4, 3]);
     }
 
     #[test]
-    fn test_remove() {
+    fn test_sort_by_is_stable() {
         let mut src = ParallelVec::new();
-        src.push((1, 2));
-        src.push((3, 4));
-        assert_eq!(src.remove(1), Some((3, 4)));
-        assert_eq!(src.remove(0), Some((1, 2)));
-        assert_eq!(src.len(), 0);
-        assert_eq!(src.remove(5), None);
+        src.extend(vec![(1, 'a'), (1, 'b'), (1, 'c')]);
+        src.sort_by(|(a, _), (b, _)| a.cmp(b));
+        assert_eq!(src.column::<1>(), &['a', 'b', 'c']);
+
+        // Interleaved keys force the permutation to be applied over longer cycles.
+        let mut src: ParallelVec<(u32, usize)> =
+            (0..50).map(|i| ((i * 7) % 3, i as usize)).collect();
+        src.sort_by(|(a, _), (b, _)| a.cmp(b));
+        for key in 0..3 {
+            let order: Vec<usize> = src
+                .iter()
+                .filter(|(k, _)| **k == key)
+                .map(|(_, i)| *i)
+                .collect();
+            assert!(order.windows(2).all(|w| w[0] < w[1]));
+        }
     }
 
     #[test]
-    fn test_iter() {
-        let mut src = ParallelVec::new();
-        src.push((1, 2));
-        src.push((3, 4));
-        src.push((5, 6));
-        src.push((7, 8));
-        let mut iter = src.iter();
-        assert_eq!(iter.next(), Some((&1, &2)));
-        assert_eq!(iter.next(), Some((&3, &4)));
-        assert_eq!(iter.next(), Some((&5, &6)));
-        assert_eq!(iter.next(), Some((&7, &8)));
-        assert_eq!(iter.next(), None);
-        assert_eq!(iter.next(), None);
-        assert_eq!(iter.next(), None);
-        // Shouldn't have removed any of them
-        assert_eq!(src.len(), 4);
+    fn test_sort_by_key_string_rows() {
+        let mut src: ParallelVec<(u32, String)> = ParallelVec::new();
+        src.push((0, String::from("c")));
+        src.push((1, String::from("a")));
+        src.push((2, String::from("b")));
+        src.sort_by_key_ref(|(_, name)| name.as_str());
+        assert_eq!(src.column::<0>(), &[1, 2, 0]);
+        src.sort_by(|(_, a), (_, b)| b.as_str().cmp(a.as_str()));
+        assert_eq!(src.column::<0>(), &[0, 2, 1]);
+        src.sort_by_key(|(_, name)| name.clone());
+        assert_eq!(src.column::<0>(), &[1, 2, 0]);
+        src.sort_unstable_by_key(|(id, _)| *id);
+        assert_eq!(src.column::<1>(), &["c", "a", "b"]);
     }
 
     #[test]
-    fn test_iters() {
+    fn test_sort_by_cached_key() {
         let mut src = ParallelVec::new();
-        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8), (9, 10), (11, 12)]);
-        let (mut a, mut b) = src.iters();
-        assert_eq!(a.next(), Some(&1));
-        assert_eq!(a.next(), Some(&3));
-        assert_eq!(a.next(), Some(&5));
-        assert_eq!(a.next(), Some(&7));
-        assert_eq!(a.next(), Some(&9));
-        assert_eq!(a.next(), Some(&11));
-        assert_eq!(a.next(), None);
-        assert_eq!(a.next(), None);
-        assert_eq!(b.next(), Some(&2));
-        assert_eq!(b.next(), Some(&4));
-        assert_eq!(b.next(), Some(&6));
-        assert_eq!(b.next(), Some(&8));
-        assert_eq!(b.next(), Some(&10));
-        assert_eq!(b.next(), Some(&12));
-        assert_eq!(b.next(), None);
-        assert_eq!(b.next(), None);
+        src.extend(vec![(2, 0), (3, 1), (1, 2), (5, 3), (4, 4)]);
+        let mut calls = 0;
+        src.sort_by_cached_key(|(a, _)| {
+            calls += 1;
+            -*a
+        });
+        assert_eq!(calls, src.len());
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[5, 4, 3, 2, 1]);
+        assert_eq!(b, &[3, 4, 1, 0, 2]);
     }
 
     #[test]
-    fn test_iter_mut() {
+    fn test_as_arrays() {
         let mut src = ParallelVec::new();
-        src.push((1, 2));
-        src.push((3, 4));
-        src.push((5, 6));
-        src.push((7, 8));
-        let mut iter = src.iter_mut();
-        assert_eq!(iter.next(), Some((&mut 1, &mut 2)));
-        assert_eq!(iter.next(), Some((&mut 3, &mut 4)));
-        assert_eq!(iter.next(), Some((&mut 5, &mut 6)));
-        assert_eq!(iter.next(), Some((&mut 7, &mut 8)));
-        assert_eq!(iter.next(), None);
-        assert_eq!(iter.next(), None);
-        assert_eq!(iter.next(), None);
-        // Shouldn't have removed any of them
-        assert_eq!(src.len(), 4);
+        src.extend(vec![(1, 2.0), (3, 4.0), (5, 6.0)]);
+        assert_eq!(src.as_arrays::<3>(), Some((&[1, 3, 5], &[2.0, 4.0, 6.0])));
+        assert_eq!(src.as_arrays::<4>(), None);
+        assert_eq!(src.as_arrays::<2>(), None);
+        assert_eq!(
+            src.index(1..3).as_arrays::<2>(),
+            Some((&[3, 5], &[4.0, 6.0]))
+        );
     }
 
     #[test]
-    fn test_shrink_to() {
+    fn test_column() {
         let mut src = ParallelVec::new();
-        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
-        src.reserve(1000);
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() >= 1000);
-        src.shrink_to(200);
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() <= 200);
-        src.shrink_to(100);
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() <= 100);
-        src.shrink_to(10);
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() <= 10);
-        src.shrink_to(1);
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() >= 4);
-        let (a, b) = src.as_slices();
-        assert_eq!(a, &[1, 3, 5, 7]);
-        assert_eq!(b, &[2, 4, 6, 8]);
+        src.extend(vec![(1u32, 2.0f64), (3, 4.0), (5, 6.0)]);
+        assert_eq!(src.column::<0>(), &[1, 3, 5]);
+        assert_eq!(src.column::<1>(), &[2.0, 4.0, 6.0]);
+        src.column_mut::<1>()[1] = 10.0;
+        assert_eq!(src.index(1), (&3, &10.0));
     }
 
     #[test]
-    fn test_shrink_to_fit() {
+    fn test_sum_field() {
         let mut src = ParallelVec::new();
-        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
-        src.reserve(1000);
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() >= 1000);
-        src.shrink_to_fit();
-        assert_eq!(src.len(), 4);
-        assert!(src.capacity() <= src.len());
-        let (a, b) = src.as_slices();
-        assert_eq!(a, &[1, 3, 5, 7]);
-        assert_eq!(b, &[2, 4, 6, 8]);
+        src.extend(vec![(1u32, 2u32), (3, 4), (5, 6), (7, 8)]);
+        assert_eq!(src.sum_field::<0>(), 16);
+        assert_eq!(src.sum_field::<1>(), 20);
+        assert_eq!(src.index(1..3).sum_field::<0>(), 8);
     }
 
     #[test]
-    fn test_truncate() {
+    fn test_fold_field() {
         let mut src = ParallelVec::new();
-        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
-        {
-            let (a, b) = src.as_slices();
-            assert_eq!(a, &[1, 3, 5, 7]);
-            assert_eq!(b, &[2, 4, 6, 8]);
-        }
-        src.truncate(2);
-        {
-            let (a, b) = src.as_slices();
-            assert_eq!(a, &[1, 3]);
-            assert_eq!(b, &[2, 4]);
-            assert_eq!(src.len(), 2);
-            assert!(src.capacity() >= 4);
-        }
+        src.extend(vec![(1u32, 2u32), (3, 4), (5, 6), (7, 8)]);
+        assert_eq!(src.fold_field::<1, _, _>(1, |acc, x| acc * x), 384);
     }
 
     #[test]
-    fn test_truncate_drops() {
-        let rc = Rc::new(0);
+    fn test_binary_search() {
         let mut src = ParallelVec::new();
-        src.extend(vec![
-            (rc.clone(), rc.clone()),
-            (rc.clone(), rc.clone()),
-            (rc.clone(), rc.clone()),
-            (rc.clone(), rc.clone()),
-        ]);
-        assert_eq!(Rc::strong_count(&rc), 9);
-        src.truncate(1);
-        assert_eq!(Rc::strong_count(&rc), 3);
+        src.extend(vec![(1, 1), (1, 5), (2, 0), (2, 3), (4, -1)]);
+        assert_eq!(src.binary_search(&(1, 1)), Ok(0));
+        assert_eq!(src.binary_search(&(2, 3)), Ok(3));
+        assert_eq!(src.binary_search(&(4, -1)), Ok(4));
+        assert_eq!(src.binary_search(&(0, 0)), Err(0));
+        assert_eq!(src.binary_search(&(2, 1)), Err(3));
+        assert_eq!(src.binary_search(&(5, 0)), Err(5));
+        assert_eq!(src.index(2..5).binary_search(&(2, 3)), Ok(1));
     }
 
     #[test]
-    fn test_reverse() {
+    fn test_binary_search_by_key() {
         let mut src = ParallelVec::new();
-        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
-        {
-            let (a, b) = src.as_slices();
-            assert_eq!(a, &[1, 3, 5, 7]);
-            assert_eq!(b, &[2, 4, 6, 8]);
-        }
-        src.reverse();
-        {
-            let (a, b) = src.as_slices();
-            assert_eq!(a, &[7, 5, 3, 1]);
-            assert_eq!(b, &[8, 6, 4, 2]);
-        }
+        src.extend(vec![(1, 'a'), (3, 'b'), (5, 'c'), (7, 'd')]);
+        assert_eq!(src.binary_search_by_key(&5, |(a, _)| *a), Ok(2));
+        assert_eq!(src.binary_search_by_key(&4, |(a, _)| *a), Err(2));
+        assert_eq!(src.binary_search_by(|(_, b)| b.cmp(&'d')), Ok(3));
     }
 
     #[test]
@@ -772,6 +3832,30 @@ mod tests {
         assert_eq!(repeated.len(), 12);
     }
 
+    #[test]
+    fn test_extend_from_within() {
+        let rc = Rc::new(0);
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, rc.clone()), (2, rc.clone()), (3, rc.clone())]);
+        src.extend_from_within(0..2);
+        assert_eq!(src.column::<0>(), &[1, 2, 3, 1, 2]);
+        assert_eq!(Rc::strong_count(&rc), 6);
+        src.extend_from_within(3..);
+        assert_eq!(src.column::<0>(), &[1, 2, 3, 1, 2, 1, 2]);
+        src.extend_from_within(..0);
+        assert_eq!(src.len(), 7);
+        core::mem::drop(src);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_extend_from_within_panics() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4)]);
+        src.extend_from_within(1..3);
+    }
+
     #[test]
     fn test_eq() {
         let a = ParallelVec::from(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
@@ -842,6 +3926,19 @@ mod tests {
         assert_eq!(src.len(), 4);
     }
 
+    #[test]
+    fn test_swap_many_unchecked() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        unsafe {
+            src.swap_many_unchecked(&[(1, 2), (0, 3), (2, 2)]);
+        }
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[7, 5, 3, 1]);
+        assert_eq!(b, &[8, 6, 4, 2]);
+        assert_eq!(src.len(), 4);
+    }
+
     #[test]
     #[should_panic]
     fn test_swap_panics() {
@@ -875,6 +3972,52 @@ mod tests {
         assert_eq!(src_b.len(), 4);
     }
 
+    #[test]
+    fn test_send() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1u64, 2u64), (3, 4), (5, 6)]);
+        let handle = std::thread::spawn(move || {
+            src.push((7, 8));
+            src
+        });
+        let src = handle.join().unwrap();
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[1, 3, 5, 7]);
+        assert_eq!(b, &[2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn test_sync() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1u64, 2u64), (3, 4), (5, 6)]);
+        let src = &src;
+        std::thread::scope(|s| {
+            s.spawn(|| assert_eq!(src.sum_field::<0>(), 9));
+            s.spawn(|| assert_eq!(src.sum_field::<1>(), 12));
+        });
+    }
+
+    #[test]
+    fn test_send_iters() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1u64, 2u64), (3, 4), (5, 6), (7, 8)]);
+        std::thread::scope(|s| {
+            let iter = src.iter();
+            let slice = src.index(2..4);
+            s.spawn(move || assert_eq!(iter.map(|(a, b)| a + b).sum::<u64>(), 36));
+            s.spawn(move || assert_eq!(slice.as_slices(), (&[5, 7][..], &[6, 8][..])));
+        });
+        std::thread::scope(|s| {
+            let iter = src.iter_mut();
+            s.spawn(move || iter.for_each(|(a, _)| *a *= 10));
+        });
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[10, 30, 50, 70]);
+        assert_eq!(b, &[2, 4, 6, 8]);
+        let handle = std::thread::spawn(move || src.into_iter().count());
+        assert_eq!(handle.join().unwrap(), 4);
+    }
+
     #[test]
     fn test_drop() {
         let rc = Rc::new(0);
@@ -907,6 +4050,21 @@ mod tests {
         assert_eq!(src_b.len(), 0);
     }
 
+    #[test]
+    fn test_append_from() {
+        let rc = Rc::new(0);
+        let mut src_a = ParallelVec::new();
+        src_a.extend(vec![(1, rc.clone()), (3, rc.clone())]);
+        let mut src_b = ParallelVec::new();
+        src_b.extend(vec![(9, rc.clone()), (2, rc.clone()), (4, rc.clone())]);
+        src_a.append_from(src_b);
+        assert_eq!(Rc::strong_count(&rc), 6);
+        assert_eq!(src_a.len(), 5);
+        assert_eq!(src_a.column::<0>(), &[1, 3, 9, 2, 4]);
+        core::mem::drop(src_a);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
     #[test]
     #[should_panic]
     fn test_swap_with_panics() {
@@ -960,6 +4118,67 @@ mod tests {
         assert_eq!(src.get_mut(5), None);
     }
 
+    #[test]
+    fn test_get_mut_pair() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        let ((a0, _), (a3, _)) = src.get_pair_mut([0, 3]).unwrap();
+        core::mem::swap(a0, a3);
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[7, 3, 5, 1]);
+        assert_eq!(b, &[2, 4, 6, 8]);
+        assert!(src.get_pair_mut([1, 1]).is_none());
+        assert!(src.get_pair_mut([1, 4]).is_none());
+        assert!(src.get_pair_mut([4, 1]).is_none());
+    }
+
+    #[test]
+    fn test_index_mut_pair() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        let ((_, b1), (_, b2)) = src.index_pair_mut([1, 2]);
+        *b1 += *b2;
+        assert_eq!(src.column::<1>(), &[2, 10, 6, 8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_mut_pair_same_index_panics() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
+        src.index_pair_mut([2, 2]);
+    }
+
+    #[test]
+    fn test_fill_range() {
+        let rc = Rc::new(0);
+        let fill = Rc::new(1);
+        let mut src = ParallelVec::new();
+        for i in 0..5 {
+            src.push((i, rc.clone()));
+        }
+        assert_eq!(Rc::strong_count(&rc), 6);
+        src.fill_range(2..4, (10, fill.clone()));
+        assert_eq!(Rc::strong_count(&rc), 4);
+        assert_eq!(Rc::strong_count(&fill), 3);
+        assert_eq!(src.column::<0>(), &[0, 1, 10, 10, 4]);
+        assert!(Rc::ptr_eq(src.index(1).1, &rc));
+        assert!(Rc::ptr_eq(src.index(2).1, &fill));
+        assert!(Rc::ptr_eq(src.index(3).1, &fill));
+        assert!(Rc::ptr_eq(src.index(4).1, &rc));
+        src.fill_range(1..1, (20, fill.clone()));
+        assert_eq!(Rc::strong_count(&fill), 3);
+        assert_eq!(src.column::<0>(), &[0, 1, 10, 10, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fill_range_panics() {
+        let mut src = ParallelVec::new();
+        src.extend(vec![(1, 2), (3, 4)]);
+        src.fill_range(1..3, (0, 0));
+    }
+
     #[test]
     fn test_first() {
         let mut src = ParallelVec::new();
@@ -1102,6 +4321,55 @@ mod tests {
         assert_eq!(vec, vec![(1, 2), (3, 4), (5, 6), (7, 8)]);
     }
 
+    #[test]
+    fn test_split_first() {
+        let mut src = ParallelVec::new();
+        assert!(src.split_first().is_none());
+        src.extend(vec![(1, 2), (3, 4), (5, 6)]);
+        let (first, rest) = src.split_first().unwrap();
+        assert_eq!(first, (&1, &2));
+        assert_eq!(rest.as_slices(), (&[3, 5][..], &[4, 6][..]));
+    }
+
+    #[test]
+    fn test_split_last() {
+        let mut src = ParallelVec::new();
+        assert!(src.split_last().is_none());
+        src.extend(vec![(1, 2), (3, 4), (5, 6)]);
+        let (last, rest) = src.split_last().unwrap();
+        assert_eq!(last, (&5, &6));
+        assert_eq!(rest.as_slices(), (&[1, 3][..], &[2, 4][..]));
+    }
+
+    #[test]
+    fn test_split_first_mut() {
+        let mut src = ParallelVec::new();
+        assert!(src.split_first_mut().is_none());
+        src.extend(vec![(1, 2), (3, 4), (5, 6)]);
+        let ((a, b), mut rest) = src.split_first_mut().unwrap();
+        *a += 1;
+        *b += 1;
+        for (c, _) in rest.iter_mut() {
+            *c += *a;
+        }
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[2, 5, 7]);
+        assert_eq!(b, &[3, 4, 6]);
+    }
+
+    #[test]
+    fn test_split_last_mut() {
+        let mut src = ParallelVec::new();
+        assert!(src.split_last_mut().is_none());
+        src.extend(vec![(1, 2), (3, 4), (5, 6)]);
+        let ((a, _), mut rest) = src.split_last_mut().unwrap();
+        *a *= 10;
+        rest.set(0, (0, 0));
+        let (a, b) = src.as_slices();
+        assert_eq!(a, &[0, 3, 50]);
+        assert_eq!(b, &[0, 4, 6]);
+    }
+
     #[test]
     fn test_slice_is_empty() {
         let mut src = ParallelVec::new();
@@ -0,0 +1,54 @@
+use parallel_vec::ParallelVec;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Counts how many times the tracked allocations are freed.
+struct CountingAlloc;
+
+static TRACKED: [AtomicUsize; 2] = [AtomicUsize::new(0), AtomicUsize::new(0)];
+static FREED: [AtomicUsize; 2] = [AtomicUsize::new(0), AtomicUsize::new(0)];
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        for (tracked, freed) in TRACKED.iter().zip(FREED.iter()) {
+            if tracked.load(Ordering::SeqCst) == ptr as usize {
+                freed.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+struct PanicOnDrop(u32);
+
+impl Drop for PanicOnDrop {
+    fn drop(&mut self) {
+        if self.0 == 2 {
+            panic!("drop panicked");
+        }
+    }
+}
+
+#[test]
+fn drop_panic_deallocates_once() {
+    let mut vec = ParallelVec::with_capacity(5);
+    for i in 0..5 {
+        vec.push((PanicOnDrop(i), i as u64));
+    }
+    let (a, b) = vec.as_slices();
+    TRACKED[0].store(a.as_ptr() as usize, Ordering::SeqCst);
+    TRACKED[1].store(b.as_ptr() as usize, Ordering::SeqCst);
+
+    let result = catch_unwind(AssertUnwindSafe(move || drop(vec)));
+    assert!(result.is_err());
+    assert_eq!(FREED[0].load(Ordering::SeqCst), 1);
+    assert_eq!(FREED[1].load(Ordering::SeqCst), 1);
+}
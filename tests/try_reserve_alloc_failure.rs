@@ -0,0 +1,78 @@
+use parallel_vec::{ParallelVec, TryReserveError};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::ptr;
+
+/// Fails the second allocation made on the current thread while armed, and
+/// counts how many times the first one is freed.
+struct FailingAlloc;
+
+thread_local! {
+    static ARMED: Cell<bool> = const { Cell::new(false) };
+    static ALLOCS: Cell<usize> = const { Cell::new(0) };
+    static FIRST: Cell<usize> = const { Cell::new(0) };
+    static FIRST_FREED: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for FailingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if !ARMED.with(Cell::get) {
+            return System.alloc(layout);
+        }
+        let count = ALLOCS.with(|allocs| {
+            allocs.set(allocs.get() + 1);
+            allocs.get()
+        });
+        match count {
+            1 => {
+                let ptr = System.alloc(layout);
+                FIRST.with(|first| first.set(ptr as usize));
+                ptr
+            }
+            _ => ptr::null_mut(),
+        }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if FIRST.with(Cell::get) == ptr as usize {
+            FIRST_FREED.with(|freed| freed.set(freed.get() + 1));
+        }
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOC: FailingAlloc = FailingAlloc;
+
+#[test]
+fn try_reserve_frees_partial_allocation() {
+    let mut vec: ParallelVec<(u32, u64)> = ParallelVec::new();
+    vec.push((1, 2));
+    vec.push((3, 4));
+    let capacity = vec.capacity();
+    let (a, b) = vec.as_slices();
+    let ptrs = (a.as_ptr(), b.as_ptr());
+
+    ARMED.with(|armed| armed.set(true));
+    let result = vec.try_reserve(100);
+    ARMED.with(|armed| armed.set(false));
+
+    // The first field was allocated, the second failed.
+    assert_eq!(ALLOCS.with(Cell::get), 2);
+    match result {
+        Err(TryReserveError::AllocError { layout }) => {
+            assert_eq!(layout.align(), core::mem::align_of::<u64>());
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+    assert_ne!(FIRST.with(Cell::get), 0);
+    assert_eq!(FIRST_FREED.with(Cell::get), 1);
+
+    // The vector is unchanged.
+    assert_eq!(vec.len(), 2);
+    assert_eq!(vec.capacity(), capacity);
+    let (a, b) = vec.as_slices();
+    assert_eq!((a.as_ptr(), b.as_ptr()), ptrs);
+    assert_eq!(a, &[1, 3]);
+    assert_eq!(b, &[2, 4]);
+}
@@ -0,0 +1,22 @@
+use core::alloc::Layout;
+use core::ptr::NonNull;
+use parallel_vec::{Allocator, Global, ParallelVec};
+
+struct Other;
+
+unsafe impl Allocator for Other {
+    fn allocate(&self, layout: Layout) -> Option<NonNull<u8>> {
+        Global.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        Global.deallocate(ptr, layout)
+    }
+}
+
+fn main() {
+    let mut a: ParallelVec<(u32, u64)> = ParallelVec::new();
+    let mut b: ParallelVec<(u32, u64), Other> = ParallelVec::new_in(Other);
+    b.push((0, 0));
+    a.append(&mut b);
+}
@@ -0,0 +1,12 @@
+use parallel_vec::ParallelVec;
+
+fn main() {
+    let mut vec: ParallelVec<(u32, u32)> = ParallelVec::new();
+    vec.push((0, 0));
+    vec.push((1, 1));
+    let mut slice = vec.slice_mut(..);
+    let [a, _] = slice.get_many_mut([0, 1]).unwrap();
+    let [b, _] = slice.get_many_mut([0, 1]).unwrap();
+    *a.0 = 5;
+    *b.0 = 6;
+}
@@ -0,0 +1,9 @@
+use parallel_vec::ParallelVec;
+
+fn main() {
+    let mut vec: ParallelVec<(u32, String)> = ParallelVec::new();
+    vec.push((0, String::from("a")));
+    let (id, _) = vec.get_mut(0).unwrap();
+    drop(vec);
+    *id = 1;
+}
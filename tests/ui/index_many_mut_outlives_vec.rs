@@ -0,0 +1,10 @@
+use parallel_vec::ParallelVec;
+
+fn main() {
+    let mut vec: ParallelVec<(u32, String)> = ParallelVec::new();
+    vec.push((0, String::from("a")));
+    vec.push((1, String::from("b")));
+    let [a, _] = vec.index_many_mut([0, 1]);
+    vec.clear();
+    a.1.push('x');
+}
@@ -0,0 +1,12 @@
+use parallel_vec::ParallelVec;
+
+fn main() {
+    let mut vec: ParallelVec<(u32, u64)> = ParallelVec::new();
+    vec.push((0, 0));
+    vec.push((1, 1));
+    let mut iter = vec.iter_mut();
+    let (a, _) = vec.as_slices_mut();
+    a[0] = 2;
+    vec.push((2, 2));
+    iter.next();
+}
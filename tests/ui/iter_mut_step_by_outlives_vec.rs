@@ -0,0 +1,12 @@
+use parallel_vec::iter::StepIterMut;
+use parallel_vec::ParallelVec;
+
+fn escape() -> StepIterMut<'static, (u32, String)> {
+    let mut vec = ParallelVec::new();
+    vec.push((0, String::from("a")));
+    vec.iter_mut_step_by(1)
+}
+
+fn main() {
+    escape();
+}
@@ -0,0 +1,12 @@
+use parallel_vec::ParallelVec;
+
+fn main() {
+    let mut vec: ParallelVec<(u32, u64)> = ParallelVec::new();
+    vec.push((0, 0));
+    vec.push((1, 1));
+    let mut slice = vec.index_mut(0..1);
+    slice.push((2, 2));
+    slice.reserve(1);
+    slice.insert(0, (3, 3));
+    slice.remove(0);
+}
@@ -0,0 +1,15 @@
+use parallel_vec::ParallelVec;
+use std::cell::RefCell;
+
+fn main() {
+    let mut vec: ParallelVec<(u32, String)> = ParallelVec::new();
+    vec.push((0, String::from("b")));
+    vec.push((1, String::from("a")));
+    // The rows are moved by the sort, so references to them must not escape
+    // the key function.
+    let names = RefCell::new(Vec::new());
+    vec.sort_by_key(|(id, name)| {
+        names.borrow_mut().push(name);
+        *id
+    });
+}